@@ -0,0 +1,58 @@
+//! Throughput of each field validator over a mix of valid and invalid inputs.
+//!
+//! Run with `cargo bench`; criterion reports the number of inputs validated per second.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use regular_expressions::validate_odd_ion_words;
+use regular_expressions::scan;
+use regular_expressions::validator;
+
+/// Returns sample inputs for the validator named `name`, roughly half of them valid.
+fn samples(name: &str) -> &'static [&'static str] {
+    match name {
+        "ssn" => &["123-45-6788", "123 01 6281", "167180009", "666-45-6789", "123-00-6789", "12-345-6789"],
+        "phone" => &["(206) 301-1473", "509-331-1383", "2063311383", "101-015-9846", "206-555-010"],
+        "email" => &["notafed@fbi.gov", "not_a_fed@fed-fbi.gov", "notafed@fbi..gov", "notafbi.gov"],
+        "name" => &["Doe, John, W", "Roe, Joe, W, H, J", "Roe Joe W H J K", "Roe;Joe;W"],
+        "date" => &["12-31-2021", "02/29/2020", "02-30-2021", "13/01/2021", "03252021"],
+        "address" => &["1234 Elmwood Road", "7890 Pine Blvd", "Cedar Road", "5678 Elm R"],
+        "city-state-zip" => &["Seattle, WA 98101", "New York, NY 10001-1234", "Miami, FLA 33101", "Boston MA 02101"],
+        "military-time" => &["0000", "2359", "2500", "abcd"],
+        "currency" => &["$1,234,567.89", "$1000", "$12,34.56", "1234.56"],
+        "url" => &["https://www.example.com/path?query=value", "www.example.com", "ftp://www.example.com", "http://www.example"],
+        "international-phone" => &["+442079460018", "+49 30 123456", "(206) 301-1473", "+44 20 7946", "not a number"],
+        "password" => &["A1b@c$d2E#", "A1b @c #D2", "A1#@bcdefE", "a1b#c2d$3"],
+        _ => &[],
+    }
+}
+
+fn bench_validators(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate");
+    for validator in validator::all() {
+        let inputs = samples(validator.name());
+        group.throughput(Throughput::Elements(inputs.len() as u64));
+        group.bench_function(validator.name(), |b| {
+            b.iter(|| {
+                for input in inputs {
+                    let _ = black_box(validator.validate(black_box(input)));
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn bench_scan(c: &mut Criterion) {
+    let text = "The ablation and ligation of the cation required differentiation, an option, and fusion.";
+    let ticket = "Customer jo@example.com (SSN 123-45-6788) called from (206) 301-1473 on 02/29/2020 about a \
+                  $1,234.56 refund to Seattle, WA 98101; see https://www.example.com/tickets?id=42 for details.";
+    let mut group = c.benchmark_group("scan");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("odd-ion-words", |b| b.iter(|| validate_odd_ion_words(black_box(text))));
+    group.throughput(Throughput::Bytes(ticket.len() as u64));
+    group.bench_function("all-fields", |b| b.iter(|| scan::scan(black_box(ticket))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_validators, bench_scan);
+criterion_main!(benches);
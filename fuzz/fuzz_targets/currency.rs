@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    regular_expressions_fuzz::check_validator("currency", input);
+    regular_expressions_fuzz::check_currency_value(input);
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use regular_expressions::temporal::{parse_date, validate_card_expiry, DateOrder, UsDate};
+
+fuzz_target!(|input: &str| {
+    regular_expressions_fuzz::check_validator("date", input);
+    for order in [DateOrder::MonthFirst, DateOrder::DayFirst, DateOrder::Either] {
+        let _ = parse_date(input, order);
+    }
+    let _ = validate_card_expiry(input, UsDate::new(2024, 1, 1).unwrap());
+});
@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    for word in regular_expressions::validate_odd_ion_words(input) {
+        assert!(word.ends_with("ion"), "found {:?} in {:?}", word, input);
+    }
+});
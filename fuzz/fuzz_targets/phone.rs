@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    regular_expressions_fuzz::check_validator("phone", input);
+    regular_expressions_fuzz::check_phone_extension(input);
+});
@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use regular_expressions::redact::{redact, Mask};
+use regular_expressions::scan::scan;
+
+fuzz_target!(|input: &str| {
+    let findings = scan(input);
+    for pair in findings.windows(2) {
+        assert!(pair[0].span().end <= pair[1].span().start, "overlapping findings in {:?}", input);
+    }
+    for finding in &findings {
+        assert_eq!(&input[finding.span().start..finding.span().end], finding.as_str());
+    }
+    for mask in [Mask::Partial, Mask::Hash, Mask::Placeholder] {
+        let _ = redact(input, mask);
+    }
+});
@@ -0,0 +1,54 @@
+//! Invariants shared by the fuzz targets. Run a target with `cargo +nightly fuzz run <target>`
+//! from this directory; crashes are written to `artifacts/<target>/`. Pass
+//! `-- -dict=digits.dict` to also try Arabic-Indic and fullwidth digits, which the validators'
+//! patterns once accepted and then failed to parse.
+
+use regular_expressions::validator;
+
+/// Runs the validator named `name` on `input` and panics if any of its invariants break.
+///
+/// # Description
+///
+/// * Validating and normalizing never panic.
+/// * Normalizing succeeds exactly when validating does.
+/// * A normalized value is valid and normalizes to itself.
+/// * The span of an error lies on character boundaries within the input.
+pub fn check_validator(name: &str, input: &str) {
+    let validator = validator::by_name(name).unwrap_or_else(|| panic!("no validator named {:?}", name));
+    match validator.validate(input) {
+        Ok(()) => {
+            let normalized = validator.normalize(input).expect("normalize rejected a valid input");
+            assert!(validator.validate(&normalized).is_ok(), "{:?} normalized to invalid {:?}", input, normalized);
+            assert_eq!(validator.normalize(&normalized).unwrap(), normalized, "normalize is not idempotent");
+            let _ = validator.components(input);
+        }
+        Err(err) => {
+            assert!(validator.normalize(input).is_err(), "normalize accepted an invalid input");
+            let span = err.span();
+            assert!(span.start <= span.end && span.end <= input.len(), "span {:?} outside {:?}", span, input);
+            assert!(input.is_char_boundary(span.start) && input.is_char_boundary(span.end));
+        }
+    }
+}
+
+/// Checks that a valid currency amount holds exactly the digits written in `input`, so none are
+/// silently dropped.
+pub fn check_currency_value(input: &str) {
+    if let Ok(amount) = regular_expressions::validate_currency(input) {
+        // Amounts without cents are whole dollars, so their digits gain two zeros as cents.
+        let cents = if input.contains('.') { "" } else { "00" };
+        let written: String = input.chars().filter(|ch| ch.is_numeric()).chain(cents.chars()).collect();
+        let normalized = amount.cents().to_string();
+        assert_eq!(written.trim_start_matches('0'), normalized.trim_start_matches('0'), "{:?}", input);
+    }
+}
+
+/// Checks that the extension of a valid phone number is the digits that end `input`.
+pub fn check_phone_extension(input: &str) {
+    if let Ok(number) = regular_expressions::validate_phone_number(input) {
+        if let Some(extension) = number.extension() {
+            let extension: &str = extension.as_ref();
+            assert!(!extension.is_empty() && input.trim_end().ends_with(extension), "{:?} has extension {:?}", input, extension);
+        }
+    }
+}
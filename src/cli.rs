@@ -0,0 +1,10 @@
+//! Modes of the command-line binary. Each submodule is a thin layer over the library.
+
+pub mod check;
+pub mod csv;
+pub mod generate;
+pub mod interactive;
+pub mod output;
+pub mod pseudonymize;
+pub mod redact;
+pub mod scan;
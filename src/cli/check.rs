@@ -0,0 +1,503 @@
+//! The per-validator subcommands, e.g. `regular_expressions ssn 123-45-6789`.
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use clap::Args;
+use phonenumber::country;
+use regular_expressions::contact::{
+    format_phone_number, EmailLevel, EmailValidator, InternationalPhoneValidator, PhoneFormat, PhoneValidator,
+};
+use regular_expressions::mailbox::{EmailClassifier, EmailNormalizer, EmailSuggester, EmailSuggestion};
+use regular_expressions::identity::SsnValidator;
+use regular_expressions::temporal::{parse_date, validate_card_expiry, DateConstraints, DateOrder, DateValidator, UsDate};
+use regular_expressions::validator::{SeparatorMode, Validator};
+use regular_expressions::{
+    validate_international_phone_number, validate_odd_ion_words, validate_phone_number_with, validate_ssn_with, Span,
+    ValidationError,
+};
+use serde_json::json;
+
+use super::output::{self, Checked, OutputFormat};
+
+/// Arguments shared by every validator subcommand.
+#[derive(Debug, Args)]
+pub struct CheckArgs {
+    /// Values to validate. If none are given, each line of stdin is validated.
+    pub values: Vec<String>,
+
+    /// Print nothing; only report validity through the exit code.
+    #[arg(short, long)]
+    pub quiet: bool,
+}
+
+/// Arguments of the `ssn` subcommand.
+#[derive(Debug, Args)]
+pub struct SsnArgs {
+    #[command(flatten)]
+    pub check: CheckArgs,
+
+    /// How strictly to check separators: `strict` requires them to be used consistently and
+    /// parentheses to be balanced.
+    #[arg(long, value_enum, default_value_t = SeparatorMode::Lenient)]
+    pub separators: SeparatorMode,
+}
+
+/// Arguments of the `date` subcommand.
+#[derive(Debug, Args)]
+pub struct DateArgs {
+    #[command(flatten)]
+    pub check: CheckArgs,
+
+    /// Also accept ISO 8601 dates, unpadded and day-first numeric dates and month names, such as
+    /// 2023-03-05, 5.3.2023 and March 5, 2023, printing each in ISO 8601 form.
+    #[arg(long)]
+    pub flexible: bool,
+
+    /// Which order to read the month and day of numeric dates in with `--flexible`. Ambiguous
+    /// dates such as 03/04/2023 are followed by their other reading after a tab, or rejected
+    /// with `either`.
+    #[arg(long, value_enum, default_value_t = DateOrder::MonthFirst, requires = "flexible")]
+    pub order: DateOrder,
+
+    /// Reject dates after today.
+    #[arg(long)]
+    pub not_in_future: bool,
+
+    /// Reject dates less than this many years before today, e.g. birthdates of minors.
+    #[arg(long, value_name = "YEARS")]
+    pub min_age: Option<u16>,
+
+    /// Reject dates before this MM/DD/YYYY date.
+    #[arg(long, value_name = "DATE")]
+    pub not_before: Option<UsDate>,
+
+    /// Reject dates after this MM/DD/YYYY date.
+    #[arg(long, value_name = "DATE")]
+    pub not_after: Option<UsDate>,
+
+    /// Reject Saturdays and Sundays.
+    #[arg(long)]
+    pub business_days: bool,
+
+    /// The MM/DD/YYYY date to count `--not-in-future` and `--min-age` from, instead of today in UTC.
+    #[arg(long, value_name = "DATE")]
+    pub today: Option<UsDate>,
+}
+
+impl DateArgs {
+    /// Returns the constraints requested by the flags.
+    fn constraints(&self) -> DateConstraints {
+        let mut constraints = DateConstraints::new();
+        if let Some(today) = self.today {
+            constraints = constraints.with_today(today);
+        }
+        if self.not_in_future {
+            constraints = constraints.not_in_future();
+        }
+        if let Some(years) = self.min_age {
+            constraints = constraints.at_least_years_ago(years);
+        }
+        if let Some(earliest) = self.not_before {
+            constraints = constraints.not_before(earliest);
+        }
+        if let Some(latest) = self.not_after {
+            constraints = constraints.not_after(latest);
+        }
+        if self.business_days {
+            constraints = constraints.business_days_only();
+        }
+        constraints
+    }
+}
+
+/// Arguments of the `card-expiry` subcommand.
+#[derive(Debug, Args)]
+pub struct CardExpiryArgs {
+    #[command(flatten)]
+    pub check: CheckArgs,
+
+    /// The MM/DD/YYYY date to check expiry against, instead of today in UTC.
+    #[arg(long, value_name = "DATE")]
+    pub today: Option<UsDate>,
+}
+
+/// Arguments of the `phone` subcommand.
+#[derive(Debug, Args)]
+pub struct PhoneArgs {
+    #[command(flatten)]
+    pub check: CheckArgs,
+
+    /// How to print valid phone numbers.
+    #[arg(long, value_enum, default_value_t = PhoneFormat::National)]
+    pub format: PhoneFormat,
+
+    /// How strictly to check separators: `strict` requires them to be used consistently and
+    /// parentheses to be balanced.
+    #[arg(long, value_enum, default_value_t = SeparatorMode::Lenient)]
+    pub separators: SeparatorMode,
+}
+
+/// Arguments of the `email` subcommand.
+#[derive(Debug, Args)]
+pub struct EmailArgs {
+    #[command(flatten)]
+    pub check: CheckArgs,
+
+    /// How strictly to check addresses: `html5` accepts what browser forms accept, `rfc5322`
+    /// also accepts quoted prefixes and IP address literals.
+    #[arg(long, value_enum, default_value_t = EmailLevel::Project)]
+    pub level: EmailLevel,
+
+    /// Also accept internationalized addresses such as josé@bücher.de, printing their domain in
+    /// Unicode form.
+    #[arg(long)]
+    pub unicode: bool,
+
+    /// Print the canonical key of each mailbox instead, applying the rules of known providers
+    /// such as ignoring dots and `+tags` in Gmail addresses.
+    #[arg(long, conflicts_with_all = ["classify", "suggest"])]
+    pub canonical: bool,
+
+    /// Also flag disposable, role and free-mail addresses, using the lists bundled with the
+    /// library. In text format the flags follow each address after a tab.
+    #[arg(long, conflicts_with = "suggest")]
+    pub classify: bool,
+
+    /// Also suggest corrections of mistyped domains, such as gmail.com for gmial.com, best
+    /// first. In text format the suggestions follow each address after a tab.
+    #[arg(long)]
+    pub suggest: bool,
+}
+
+/// Arguments of the `url` subcommand.
+#[derive(Debug, Args)]
+pub struct UrlArgs {
+    #[command(flatten)]
+    pub check: CheckArgs,
+
+    /// Also accept internationalized hosts such as bücher.de, printing them in Unicode form.
+    #[arg(long)]
+    pub unicode: bool,
+}
+
+/// Arguments of the `international-phone` subcommand.
+#[derive(Debug, Args)]
+pub struct InternationalPhoneArgs {
+    #[command(flatten)]
+    pub check: CheckArgs,
+
+    /// The country of numbers written without a `+` country calling code, as an ISO 3166 code.
+    #[arg(long, default_value = "US", value_parser = parse_region)]
+    pub region: country::Id,
+
+    /// How to print valid phone numbers.
+    #[arg(long, value_enum, default_value_t = PhoneFormat::E164)]
+    pub format: PhoneFormat,
+}
+
+/// Parses an ISO 3166 country code such as `GB` or `de`.
+pub fn parse_region(region: &str) -> Result<country::Id, String> {
+    region.to_ascii_uppercase().parse().map_err(|_| format!("unknown region {:?}, expected a code such as US or GB", region))
+}
+
+/// Runs a validator subcommand, printing the normalized form of each valid value.
+///
+/// # Returns
+///
+/// * `ExitCode` - Success if every value is valid, and 1 otherwise.
+pub fn run(validator: &dyn Validator, args: CheckArgs, format: OutputFormat) -> ExitCode {
+    run_with(validator.name(), args, format, |value| output::check(validator, value))
+}
+
+/// Runs the `ssn` subcommand, printing valid SSNs in the dashed form.
+pub fn run_ssn(args: SsnArgs, format: OutputFormat) -> ExitCode {
+    run_with(SsnValidator.name(), args.check, format, |value| {
+        let ssn = validate_ssn_with(value, args.separators)?;
+        Ok(Checked { normalized: ssn.to_string(), components: SsnValidator.components(value)? })
+    })
+}
+
+/// Runs the `date` subcommand, printing valid dates as MM/DD/YYYY or, with `--flexible`, in ISO 8601 form.
+pub fn run_date(args: DateArgs, format: OutputFormat) -> ExitCode {
+    let constraints = args.constraints();
+    if !args.flexible {
+        return run_with(DateValidator.name(), args.check, format, |value| {
+            constraints.validate(value)?;
+            output::check(&DateValidator, value)
+        });
+    }
+    run_with(DateValidator.name(), args.check, format, |value| {
+        let parsed = parse_date(value, args.order)?;
+        let date = parsed.date();
+        constraints.check(date).map_err(|err| ValidationError::new(err, Span::whole(value)))?;
+        let mut normalized = date.iso8601();
+        let mut components = vec![
+            ("month", date.month().to_string()),
+            ("day", date.day().to_string()),
+            ("year", date.year().to_string()),
+            ("format", parsed.format().name().to_string()),
+        ];
+        if let Some(alternative) = parsed.alternative() {
+            if format == OutputFormat::Text {
+                normalized = with_column(normalized, &[&alternative.iso8601()]);
+            }
+            components.push(("alternative", alternative.iso8601()));
+        }
+        Ok(Checked { normalized, components })
+    })
+}
+
+/// Runs the `card-expiry` subcommand, printing unexpired expiry dates as MM/YY.
+pub fn run_card_expiry(args: CardExpiryArgs, format: OutputFormat) -> ExitCode {
+    let today = args.today.unwrap_or_else(UsDate::today);
+    run_with("card-expiry", args.check, format, |value| {
+        let expiry = validate_card_expiry(value, today)?;
+        Ok(Checked {
+            normalized: expiry.to_string(),
+            components: vec![
+                ("month", expiry.month().to_string()),
+                ("year", expiry.year().to_string()),
+                ("last_day", expiry.last_day().iso8601()),
+            ],
+        })
+    })
+}
+
+/// Runs the `phone` subcommand, printing valid numbers in the requested format.
+pub fn run_phone(args: PhoneArgs, format: OutputFormat) -> ExitCode {
+    run_with("phone", args.check, format, |value| {
+        let phone_number = validate_phone_number_with(value, args.separators)?;
+        Ok(Checked {
+            normalized: format_phone_number(&phone_number, args.format),
+            components: PhoneValidator.components(value)?,
+        })
+    })
+}
+
+/// Runs the `email` subcommand, printing normalized addresses or, with `--canonical`, canonical keys.
+pub fn run_email(args: EmailArgs, format: OutputFormat) -> ExitCode {
+    let validator = EmailValidator::new(args.level).with_unicode(args.unicode);
+    if args.classify {
+        return run_email_classification(&validator, args, format);
+    }
+    if args.suggest {
+        return run_email_suggestions(&validator, args, format);
+    }
+    if !args.canonical {
+        return run(&validator, args.check, format);
+    }
+    let normalizer = EmailNormalizer::with_known_providers().with_level(args.level);
+    run_with(validator.name(), args.check, format, |value| {
+        let email = normalizer.normalize(value)?;
+        let mut components = vec![("prefix", email.prefix().to_string()), ("domain", email.domain().to_string())];
+        if let Some(subaddress) = email.subaddress() {
+            components.push(("subaddress", subaddress.to_string()));
+        }
+        Ok(Checked { normalized: email.canonical_key().to_string(), components })
+    })
+}
+
+/// Runs the `email` subcommand with `--classify`, adding the flags of each address.
+fn run_email_classification(validator: &EmailValidator, args: EmailArgs, format: OutputFormat) -> ExitCode {
+    let classifier = EmailClassifier::new().with_level(args.level);
+    run_with(validator.name(), args.check, format, |value| {
+        let mut components = validator.components(value)?;
+        let classification = classifier.classify(value)?;
+        let flags = [
+            ("disposable", classification.is_disposable()),
+            ("role", classification.is_role_account()),
+            ("free-mail", classification.is_free_mail()),
+        ];
+        let mut normalized = validator.normalize(value)?;
+        if format == OutputFormat::Text {
+            let set: Vec<&str> = flags.iter().filter(|(_, set)| *set).map(|(flag, _)| *flag).collect();
+            normalized = with_column(normalized, &set);
+        }
+        components.extend(flags.iter().map(|(flag, set)| (*flag, set.to_string())));
+        Ok(Checked { normalized, components })
+    })
+}
+
+/// Runs the `email` subcommand with `--suggest`, adding the corrections of each address.
+fn run_email_suggestions(validator: &EmailValidator, args: EmailArgs, format: OutputFormat) -> ExitCode {
+    let suggester = EmailSuggester::new().with_level(args.level);
+    run_with(validator.name(), args.check, format, |value| {
+        let mut components = validator.components(value)?;
+        let suggestions = suggester.suggest(value)?;
+        let suggestions: Vec<&str> = suggestions.iter().map(EmailSuggestion::address).collect();
+        let mut normalized = validator.normalize(value)?;
+        if format == OutputFormat::Text {
+            normalized = with_column(normalized, &suggestions);
+        }
+        components.push(("suggestions", suggestions.join(",")));
+        Ok(Checked { normalized, components })
+    })
+}
+
+/// Appends `values` to `line` as a tab-separated, comma-joined column, `-` if there are none.
+fn with_column(line: String, values: &[&str]) -> String {
+    format!("{}\t{}", line, if values.is_empty() { "-".to_string() } else { values.join(",") })
+}
+
+/// Runs the `international-phone` subcommand, printing valid numbers in the requested format.
+pub fn run_international_phone(args: InternationalPhoneArgs, format: OutputFormat) -> ExitCode {
+    let validator = InternationalPhoneValidator::new(args.region);
+    run_with(validator.name(), args.check, format, |value| {
+        let phone = validate_international_phone_number(value, args.region)?;
+        Ok(Checked { normalized: format_phone_number(phone.number(), args.format), components: validator.components(value)? })
+    })
+}
+
+/// Runs the `odd-ion-words` subcommand, printing each matching word of each text.
+///
+/// # Returns
+///
+/// * `ExitCode` - Success if at least one word was found, and 1 otherwise.
+pub fn run_odd_ion_words(args: CheckArgs, format: OutputFormat) -> ExitCode {
+    let mut found = false;
+    let mut stdout = io::stdout().lock();
+    for text in values(&args) {
+        let words: Vec<String> = validate_odd_ion_words(&text).iter().map(|word| word.trim().to_string()).collect();
+        found |= !words.is_empty();
+        if args.quiet {
+            continue;
+        }
+        match format {
+            OutputFormat::Text => {
+                for word in &words {
+                    let _ = writeln!(stdout, "{}", word);
+                }
+            }
+            OutputFormat::Json => {
+                let _ = writeln!(stdout, "{}", json!({ "validator": "odd-ion-words", "input": text, "matches": words }));
+            }
+        }
+    }
+    exit_code(found)
+}
+
+/// Validates each value of `args` with `check`, writing to stdout and stderr.
+pub fn run_with(
+    name: &str,
+    args: CheckArgs,
+    format: OutputFormat,
+    check: impl Fn(&str) -> Result<Checked, ValidationError>,
+) -> ExitCode {
+    let values = values(&args);
+    let valid = if args.quiet {
+        check_values(name, &values, format, check, io::sink(), io::sink())
+    } else {
+        check_values(name, &values, format, check, io::stdout().lock(), io::stderr().lock())
+    };
+    exit_code(valid)
+}
+
+/// Returns the values given as arguments, or the non-empty lines of stdin if there are none.
+fn values(args: &CheckArgs) -> Vec<String> {
+    if !args.values.is_empty() {
+        return args.values.iter().map(|value| value.trim().to_string()).collect();
+    }
+    io::stdin()
+        .lock()
+        .lines()
+        .map_while(Result::ok)
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Validates each of `values` with the validator `name`.
+///
+/// # Description
+///
+/// * In text format, writes the normalized form of valid values to `out` and the reason invalid
+///   ones failed to `err`.
+/// * In JSON format, writes one [`output::result_json`] object per value to `out`, valid or not.
+///
+/// # Returns
+///
+/// * `bool` - Returns `true` if every value is valid.
+pub fn check_values<O: Write, E: Write>(
+    name: &str,
+    values: &[String],
+    format: OutputFormat,
+    check: impl Fn(&str) -> Result<Checked, ValidationError>,
+    mut out: O,
+    mut err: E,
+) -> bool {
+    let mut all_valid = true;
+    for value in values {
+        let result = check(value);
+        all_valid &= result.is_ok();
+        match (format, &result) {
+            (OutputFormat::Json, _) => {
+                let _ = writeln!(out, "{}", output::result_json(name, value, &result));
+            }
+            (OutputFormat::Text, Ok(checked)) => {
+                let _ = writeln!(out, "{}", checked.normalized);
+            }
+            (OutputFormat::Text, Err(error)) => {
+                let _ = writeln!(err, "invalid {} {:?}: {}", name, value, error);
+            }
+        }
+    }
+    all_valid
+}
+
+fn exit_code(success: bool) -> ExitCode {
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regular_expressions::identity::SsnValidator;
+
+    fn check_as(format: OutputFormat, values: &[&str]) -> (bool, String, String) {
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        let (mut out, mut err) = (Vec::new(), Vec::new());
+        let valid = check_values("ssn", &values, format, |value| output::check(&SsnValidator, value), &mut out, &mut err);
+        (valid, String::from_utf8(out).unwrap(), String::from_utf8(err).unwrap())
+    }
+
+    fn check(values: &[&str]) -> (bool, String, String) {
+        check_as(OutputFormat::Text, values)
+    }
+
+    #[test]
+    fn test_check_values_prints_normalized_values() {
+        assert_eq!(check(&["123 45 6788", "724-34-8124"]), (true, "123-45-6788\n724-34-8124\n".to_string(), String::new()));
+    }
+
+    #[test]
+    fn test_check_values_reports_invalid_values() {
+        let (valid, out, err) = check(&["123 45 6788", "666-12-3456"]);
+        assert!(!valid);
+        assert_eq!(out, "123-45-6788\n");
+        assert_eq!(err, "invalid ssn \"666-12-3456\": SSN area number 666 is never issued (at bytes 0..3)\n");
+    }
+
+    #[test]
+    fn test_check_values_prints_json_lines() {
+        let (valid, out, err) = check_as(OutputFormat::Json, &["123 45 6788", "666-12-3456"]);
+        assert!(!valid);
+        assert!(err.is_empty());
+        let lines: Vec<serde_json::Value> = out.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["normalized"], "123-45-6788");
+        assert_eq!(lines[0]["components"]["serial"], "6788");
+        assert_eq!(lines[1]["valid"], false);
+        assert_eq!(lines[1]["errors"][0]["span"]["end"], 3);
+    }
+
+    #[test]
+    fn test_parse_region() {
+        assert_eq!(parse_region("gb"), Ok(country::GB));
+        assert!(parse_region("XX").is_err());
+    }
+}
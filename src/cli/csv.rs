@@ -0,0 +1,310 @@
+//! The `csv` subcommand: validates mapped columns of a CSV file in batch.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Args;
+use regular_expressions::validator::{self, Validator};
+use regular_expressions::ValidationError;
+use serde_json::{json, Value};
+
+use super::output::{self, OutputFormat};
+
+/// A CSV column and the validator that checks it, given on the command line as `HEADER=VALIDATOR`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnMapping {
+    /// The header of the column to validate.
+    pub header: String,
+    /// The [`Validator::name`] of the validator to check the column with.
+    pub validator: String,
+}
+
+/// Parses a `HEADER=VALIDATOR` column mapping, checking that the validator exists.
+pub fn parse_column(mapping: &str) -> Result<ColumnMapping, String> {
+    let (header, name) = mapping
+        .split_once('=')
+        .ok_or_else(|| format!("expected HEADER=VALIDATOR, found {:?}", mapping))?;
+    if validator::by_name(name).is_none() {
+        let names: Vec<&str> = validator::all().iter().map(|validator| validator.name()).collect();
+        return Err(format!("unknown validator {:?}, expected one of: {}", name, names.join(", ")));
+    }
+    Ok(ColumnMapping { header: header.to_string(), validator: name.to_string() })
+}
+
+/// Arguments of the `csv` subcommand.
+#[derive(Debug, Args)]
+pub struct CsvArgs {
+    /// A column to validate, as HEADER=VALIDATOR (e.g. `email=email`). May be repeated.
+    #[arg(long = "column", value_name = "HEADER=VALIDATOR", required = true, value_parser = parse_column)]
+    pub columns: Vec<ColumnMapping>,
+
+    /// Write the report of failing values to this file instead of stdout.
+    #[arg(long, value_name = "PATH")]
+    pub report: Option<PathBuf>,
+
+    /// Also write a copy of the input with every valid value in a mapped column normalized.
+    #[arg(long, value_name = "PATH")]
+    pub cleaned: Option<PathBuf>,
+
+    /// The CSV file to validate, with a header row. Use `-` to read stdin.
+    pub input: PathBuf,
+}
+
+/// Counts of what a CSV run checked.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    /// Number of data rows read, excluding the header.
+    pub rows: usize,
+    /// Number of values checked across all mapped columns.
+    pub checked: usize,
+    /// Number of values that failed validation.
+    pub invalid: usize,
+}
+
+/// Runs the `csv` subcommand.
+///
+/// # Returns
+///
+/// * `ExitCode` - Success if every value is valid, 1 if any value is invalid, and 2 if the input
+///   could not be read or the arguments do not match its header.
+pub fn run(args: CsvArgs, format: OutputFormat) -> ExitCode {
+    match run_with_files(&args, format) {
+        Ok(summary) => {
+            eprintln!("{} rows, {} values checked, {} invalid", summary.rows, summary.checked, summary.invalid);
+            if summary.invalid == 0 {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::from(1)
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Opens the files named by `args` and validates the input.
+fn run_with_files(args: &CsvArgs, format: OutputFormat) -> Result<Summary, Box<dyn Error>> {
+    let input: Box<dyn Read> = if args.input == Path::new("-") {
+        Box::new(io::stdin())
+    } else {
+        Box::new(File::open(&args.input).map_err(|err| format!("cannot open {}: {}", args.input.display(), err))?)
+    };
+    let report: Box<dyn Write> = match &args.report {
+        Some(path) => Box::new(File::create(path).map_err(|err| format!("cannot create {}: {}", path.display(), err))?),
+        None => Box::new(io::stdout()),
+    };
+    let cleaned: Option<Box<dyn Write>> = match &args.cleaned {
+        Some(path) => Some(Box::new(File::create(path).map_err(|err| format!("cannot create {}: {}", path.display(), err))?)),
+        None => None,
+    };
+    validate_csv(input, report, format, cleaned, &args.columns)
+}
+
+/// Validates the mapped columns of the CSV read from `input`.
+///
+/// # Description
+///
+/// * Writes one report row per invalid value to `report`, as CSV with the columns
+///   `line,column,value,validator,reason`. `line` is the line of the input the value is on.
+/// * In JSON format, each report row is instead an [`output::result_json`] object with the
+///   additional keys `line` and `column`, one per line, and no header is written.
+/// * If `cleaned` is given, writes the input to it with every valid value in a mapped column
+///   replaced by its normalized form, e.g. phone numbers in national format. Invalid values are
+///   copied unchanged.
+/// * Surrounding whitespace in a value is ignored.
+///
+/// # Returns
+///
+/// * `Result<Summary, Box<dyn Error>>` - Returns the counts of what was checked, or an error if the
+///   input is not valid CSV or a mapped column is missing from its header.
+pub fn validate_csv<R: Read, W: Write, C: Write>(
+    input: R,
+    report: W,
+    format: OutputFormat,
+    cleaned: Option<C>,
+    columns: &[ColumnMapping],
+) -> Result<Summary, Box<dyn Error>> {
+    let mut reader = csv::Reader::from_reader(input);
+    let headers = reader.headers()?.clone();
+
+    // Resolve each mapping to its column index and validator before reading any rows.
+    let mut checks: Vec<(usize, Box<dyn Validator>)> = Vec::with_capacity(columns.len());
+    for mapping in columns {
+        let index = headers.iter().position(|header| header == mapping.header).ok_or_else(|| {
+            let found: Vec<&str> = headers.iter().collect();
+            format!("column {:?} not found in header: {}", mapping.header, found.join(", "))
+        })?;
+        let validator = validator::by_name(&mapping.validator).ok_or_else(|| format!("unknown validator {:?}", mapping.validator))?;
+        checks.push((index, validator));
+    }
+
+    let mut report = Report::new(report, format)?;
+    let mut cleaned = cleaned.map(csv::Writer::from_writer);
+    if let Some(cleaned) = cleaned.as_mut() {
+        cleaned.write_record(&headers)?;
+    }
+
+    let mut summary = Summary::default();
+    for record in reader.records() {
+        let record = record?;
+        let line = record.position().map_or(0, |position| position.line());
+        let mut fields: Vec<String> = record.iter().map(str::to_string).collect();
+        summary.rows += 1;
+
+        for (index, validator) in &checks {
+            let Some(value) = record.get(*index) else { continue };
+            summary.checked += 1;
+            match validator.normalize(value.trim()) {
+                Ok(normalized) => fields[*index] = normalized,
+                Err(err) => {
+                    summary.invalid += 1;
+                    report.write(line, &headers[*index], value, validator.as_ref(), err)?;
+                }
+            }
+        }
+
+        if let Some(cleaned) = cleaned.as_mut() {
+            cleaned.write_record(&fields)?;
+        }
+    }
+
+    report.flush()?;
+    if let Some(cleaned) = cleaned.as_mut() {
+        cleaned.flush()?;
+    }
+    Ok(summary)
+}
+
+/// The report of failing values, written as CSV or as JSON lines.
+enum Report<W: Write> {
+    Csv(Box<csv::Writer<W>>),
+    Json(W),
+}
+
+impl<W: Write> Report<W> {
+    /// Starts a report in `format`, writing the header row if it has one.
+    fn new(writer: W, format: OutputFormat) -> Result<Report<W>, Box<dyn Error>> {
+        match format {
+            OutputFormat::Text => {
+                let mut writer = csv::Writer::from_writer(writer);
+                writer.write_record(["line", "column", "value", "validator", "reason"])?;
+                Ok(Report::Csv(Box::new(writer)))
+            }
+            OutputFormat::Json => Ok(Report::Json(writer)),
+        }
+    }
+
+    /// Reports that `value`, on `line` in `column`, failed `validator` with `err`.
+    fn write(
+        &mut self,
+        line: u64,
+        column: &str,
+        value: &str,
+        validator: &dyn Validator,
+        err: ValidationError,
+    ) -> Result<(), Box<dyn Error>> {
+        match self {
+            Report::Csv(writer) => {
+                writer.write_record([&line.to_string(), column, value, validator.name(), &err.kind().to_string()])?
+            }
+            Report::Json(writer) => {
+                let mut row = json!({ "line": line, "column": column });
+                if let (Value::Object(row), Value::Object(result)) =
+                    (&mut row, output::result_json(validator.name(), value, &Err(err)))
+                {
+                    row.extend(result);
+                }
+                writeln!(writer, "{}", row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Report::Csv(writer) => writer.flush(),
+            Report::Json(writer) => writer.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "name,email,ssn,phone\n\
+                         Doe,notafed@FBI.gov,123 45 6788,206-301-1473\n\
+                         Roe,notafed@fbi..gov,666-12-3456,(206) 301-1473\n";
+
+    fn columns() -> Vec<ColumnMapping> {
+        ["email=email", "ssn=ssn", "phone=phone"].iter().map(|mapping| parse_column(mapping).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse_column() {
+        assert_eq!(
+            parse_column("Contact Email=email").unwrap(),
+            ColumnMapping { header: "Contact Email".to_string(), validator: "email".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_parse_column_rejects_unknown_validator() {
+        assert!(parse_column("email=zodiac").unwrap_err().contains("unknown validator"));
+    }
+
+    #[test]
+    fn test_parse_column_rejects_missing_equals() {
+        assert!(parse_column("email").is_err());
+    }
+
+    #[test]
+    fn test_validate_csv_reports_failing_values() {
+        let mut report = Vec::new();
+        let summary = validate_csv(INPUT.as_bytes(), &mut report, OutputFormat::Text, None::<Vec<u8>>, &columns()).unwrap();
+        assert_eq!(summary, Summary { rows: 2, checked: 6, invalid: 2 });
+        assert_eq!(
+            String::from_utf8(report).unwrap(),
+            "line,column,value,validator,reason\n\
+             3,email,notafed@fbi..gov,email,email domain labels must not be empty\n\
+             3,ssn,666-12-3456,ssn,SSN area number 666 is never issued\n"
+        );
+    }
+
+    #[test]
+    fn test_validate_csv_reports_json_lines() {
+        let mut report = Vec::new();
+        validate_csv(INPUT.as_bytes(), &mut report, OutputFormat::Json, None::<Vec<u8>>, &columns()).unwrap();
+        let report = String::from_utf8(report).unwrap();
+        let rows: Vec<Value> = report.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1]["line"], 3);
+        assert_eq!(rows[1]["column"], "ssn");
+        assert_eq!(rows[1]["validator"], "ssn");
+        assert_eq!(rows[1]["errors"][0]["rule"], "Ssn(ForbiddenArea(666))");
+    }
+
+    #[test]
+    fn test_validate_csv_writes_cleaned_values() {
+        let mut cleaned = Vec::new();
+        validate_csv(INPUT.as_bytes(), io::sink(), OutputFormat::Text, Some(&mut cleaned), &columns()).unwrap();
+        assert_eq!(
+            String::from_utf8(cleaned).unwrap(),
+            "name,email,ssn,phone\n\
+             Doe,notafed@fbi.gov,123-45-6788,(206) 301-1473\n\
+             Roe,notafed@fbi..gov,666-12-3456,(206) 301-1473\n"
+        );
+    }
+
+    #[test]
+    fn test_validate_csv_rejects_missing_column() {
+        let columns = vec![parse_column("e-mail=email").unwrap()];
+        let err = validate_csv(INPUT.as_bytes(), io::sink(), OutputFormat::Text, None::<Vec<u8>>, &columns).unwrap_err();
+        assert!(err.to_string().contains("column \"e-mail\" not found"));
+    }
+}
@@ -0,0 +1,86 @@
+//! The `generate` subcommand: prints random valid or invalid samples of a field, for fixtures.
+
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+use clap::Args;
+use regular_expressions::generate::{Field, Generator};
+use serde_json::json;
+
+use super::output::OutputFormat;
+
+/// Arguments of the `generate` subcommand.
+#[derive(Debug, Args)]
+pub struct GenerateArgs {
+    /// The kind of field (ssn, phone, email, name, date, military-time, address, city-state-zip,
+    /// currency, url, password or odd-ion-words).
+    pub field: Field,
+
+    /// How many samples to print.
+    #[arg(long, default_value_t = 10)]
+    pub count: usize,
+
+    /// Seed of the random generator. The same seed always prints the same samples.
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+
+    /// Print samples that break one of the field's rules instead.
+    #[arg(long)]
+    pub invalid: bool,
+}
+
+/// Runs the `generate` subcommand.
+///
+/// # Returns
+///
+/// * `ExitCode` - Success once every sample is printed.
+pub fn run(args: GenerateArgs, format: OutputFormat) -> ExitCode {
+    let _ = write_samples(&args, format, io::stdout().lock());
+    ExitCode::SUCCESS
+}
+
+/// Writes one sample per line to `out`.
+///
+/// # Description
+///
+/// * In text format each line is the sample itself.
+/// * In JSON format each line is an object with the keys `field`, `valid` and `value`.
+pub fn write_samples<W: Write>(args: &GenerateArgs, format: OutputFormat, mut out: W) -> io::Result<()> {
+    let mut generator = Generator::new(args.seed);
+    for _ in 0..args.count {
+        let value = if args.invalid { generator.invalid(args.field) } else { generator.valid(args.field) };
+        match format {
+            OutputFormat::Text => writeln!(out, "{}", value)?,
+            OutputFormat::Json => {
+                writeln!(out, "{}", json!({ "field": args.field.name(), "valid": !args.invalid, "value": value }))?
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn written(invalid: bool, format: OutputFormat) -> String {
+        let args = GenerateArgs { field: Field::Ssn, count: 3, seed: 9, invalid };
+        let mut out = Vec::new();
+        write_samples(&args, format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_write_samples_is_seeded() {
+        let text = written(false, OutputFormat::Text);
+        assert_eq!(text.lines().count(), 3);
+        assert_eq!(text, written(false, OutputFormat::Text));
+        assert!(text.lines().all(|ssn| regular_expressions::validate_ssn(ssn).is_ok()));
+    }
+
+    #[test]
+    fn test_write_samples_json() {
+        let json = written(true, OutputFormat::Json);
+        assert!(json.lines().all(|line| line.starts_with(r#"{"field":"ssn","valid":false,"value":"#)));
+    }
+}
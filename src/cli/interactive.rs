@@ -0,0 +1,168 @@
+//! The interactive mode: prompts for each field in turn on stdin.
+
+use std::io;
+use phonenumber::Mode;
+use regular_expressions::{
+    validate_address, validate_city_state_zip, validate_currency, validate_date, validate_email,
+    validate_military_time, validate_name_roster, validate_odd_ion_words, validate_password,
+    validate_phone_number, validate_ssn, validate_url,
+};
+
+/// Prompts for each field in turn and reports whether it is valid.
+pub fn run() {
+    let ssn = get_ssn();
+    match validate_ssn(&ssn) {
+        Ok(_) => println!("Valid SSN"),
+        Err(err) => println!("Invalid SSN: {}", err),
+    }
+
+    println!("\nEnter your phone number: ");
+    let mut phone_input = String::new();
+    io::stdin()
+        .read_line(&mut phone_input)
+        .expect("Failed to read line");
+
+    let phone_input = phone_input.trim();
+    match validate_phone_number(phone_input) {
+        Ok(phone_number) => {
+            println!("Valid phone number: {}", phone_number.format().mode(Mode::National));
+        },
+        Err(err) => {
+            println!("Invalid phone number: {}", err);
+        }
+    }
+
+    println!("\nEnter your email: ");
+    let mut email_input = String::new();
+    io::stdin()
+        .read_line(&mut email_input)
+        .expect("Failed to read line");
+
+    let email_input = email_input.trim();
+    match validate_email(email_input) {
+        Ok(()) => println!("Valid email"),
+        Err(err) => println!("Invalid email: {}", err),
+    }
+
+    println!("\nEnter a class roster name (Last name, First name, MI):");
+    let mut roster_name_input = String::new();
+    io::stdin().read_line(&mut roster_name_input).expect("Failed to read input");
+
+    let roster_name_input = roster_name_input.trim();
+    match validate_name_roster(roster_name_input) {
+        Ok(()) => println!("The class roster name is valid."),
+        Err(err) => println!("The class roster name is invalid: {}", err),
+    }
+
+    println!("\nEnter a date (MM-DD-YYYY or MM/DD/YYYY):");
+    let mut date_input = String::new();
+    io::stdin().read_line(&mut date_input).expect("Failed to read input");
+
+    let date_input = date_input.trim();
+    match validate_date(date_input) {
+        Ok(_) => println!("The date is valid."),
+        Err(err) => println!("The date is invalid: {}", err),
+    }
+
+    println!("\nEnter a house address (Street number, street name, and abbreviation, e.g. 1234 Elm street):");
+    let mut address_input = String::new();
+    io::stdin().read_line(&mut address_input).expect("Failed to read input");
+
+    let address_input = address_input.trim();
+    match validate_address(address_input) {
+        Ok(()) => println!("The house address is valid."),
+        Err(err) => println!("The house address is invalid: {}", err),
+    }
+
+    println!("\nEnter a city, state abbreviation, and zip code (e.g. Seattle, WA 98101):");
+    let mut city_state_zip_input = String::new();
+    io::stdin().read_line(&mut city_state_zip_input).expect("Failed to read input");
+
+    let city_state_zip_input = city_state_zip_input.trim();
+    match validate_city_state_zip(city_state_zip_input) {
+        Ok(()) => println!("The city, state, and zip code are valid."),
+        Err(err) => println!("The city, state, and zip code are invalid: {}", err),
+    }
+
+    println!("\nEnter a military time without colons and with leading zeros for times under 10 (e.g. 0123):");
+    let mut military_time_input = String::new();
+    io::stdin().read_line(&mut military_time_input).expect("Failed to read input");
+
+    let military_time_input = military_time_input.trim();
+    match validate_military_time(military_time_input) {
+        Ok(_) => println!("The military time is valid."),
+        Err(err) => println!("The military time is invalid: {}", err),
+    }
+
+    println!("\nEnter a US currency amount down to the penny (e.g. $123,456,789.23):");
+    let mut currency_input = String::new();
+    io::stdin().read_line(&mut currency_input).expect("Failed to read input");
+
+    let currency_input = currency_input.trim();
+    match validate_currency(currency_input) {
+        Ok(_) => println!("The currency amount is valid."),
+        Err(err) => println!("The currency amount is invalid: {}", err),
+    }
+
+    println!("\nEnter a URL, optionally including http:// or https:// (e.g. https://www.example.com):");
+    let mut url_input = String::new();
+    io::stdin().read_line(&mut url_input).expect("Failed to read input");
+
+    let url_input = url_input.trim();
+    match validate_url(url_input) {
+        Ok(()) => println!("The URL is valid."),
+        Err(err) => println!("The URL is invalid: {}", err),
+    }
+
+    println!("\nEnter a password with at least 10 characters, including at least one upper case \
+              character, one lower case character, \none digit, one punctuation mark, and no more \
+              than 3 consecutive lower case characters:");
+    let mut password_input = String::new();
+    io::stdin().read_line(&mut password_input).expect("Failed to read input");
+
+    let password_input = password_input.trim();
+    match validate_password(password_input) {
+        Ok(()) => println!("The password is valid."),
+        Err(err) => println!("The password is invalid: {}", err),
+    }
+
+    println!("\nEnter a text to find all words containing an odd number of alphabetic characters and ending in 'ion':");
+    let mut text_input = String::new();
+    io::stdin().read_line(&mut text_input).expect("Failed to read input");
+
+    let text_input = text_input.trim();
+    let odd_ion_words = validate_odd_ion_words(text_input);
+    if !odd_ion_words.is_empty() {
+        println!("Odd 'ion' words found:");
+        for word in odd_ion_words {
+            println!("{ }", word);
+        }
+    } else {
+        println!("No odd 'ion' words found.");
+    }
+}
+
+/// Retrieves a Social Security Number (SSN) from the user.
+///
+/// # Description
+///
+/// * Prompts the user to enter their SSN.
+/// * Reads the user's input and trims any leading or trailing whitespace.
+///
+/// # Returns
+///
+/// * `String` - Returns the user's inputted SSN as a String.
+fn get_ssn() -> String {
+    let mut ssn = String::new();
+    println!("Enter your SSN: ");
+
+    // Read the user's input and store it in the 'ssn' variable.
+    // If reading the input fails, an error message will be displayed.
+    io::stdin()
+        .read_line(&mut ssn)
+        .expect("Failed to read line");
+
+    // Trim any leading or trailing whitespace from the input and convert it to a String.
+    let ssn = ssn.trim();
+    ssn.to_string()
+}
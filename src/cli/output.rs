@@ -0,0 +1,103 @@
+//! The `--output` formats shared by the subcommands, and the JSON form of a checked value.
+
+use clap::ValueEnum;
+use regular_expressions::validator::Validator;
+use regular_expressions::ValidationError;
+use serde_json::{json, Map, Value};
+
+/// How subcommands print their results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable lines.
+    #[default]
+    Text,
+    /// One JSON object per line.
+    Json,
+}
+
+/// A valid value in normalized form, together with its named parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checked {
+    /// The value rewritten in the canonical form for its field kind.
+    pub normalized: String,
+    /// The name and value of each part, as returned by [`Validator::components`].
+    pub components: Vec<(&'static str, String)>,
+}
+
+/// Normalizes `input` with `validator` and splits it into its named parts.
+pub fn check(validator: &dyn Validator, input: &str) -> Result<Checked, ValidationError> {
+    Ok(Checked { normalized: validator.normalize(input)?, components: validator.components(input)? })
+}
+
+/// Describes the result of checking `input` with the validator `name` as a JSON object.
+///
+/// # Description
+///
+/// * A valid value produces
+///   `{"validator":"ssn","input":"123 45 6788","valid":true,"normalized":"123-45-6788","components":{"area":"123",...},"errors":[]}`.
+/// * An invalid value has `"valid":false`, a `null` normalized value, no components, and one entry in
+///   `errors` per broken rule, e.g. `{"rule":"Ssn(ForbiddenArea(666))","message":"SSN area number 666 is never issued","span":{"start":0,"end":3}}`.
+pub fn result_json(name: &str, input: &str, result: &Result<Checked, ValidationError>) -> Value {
+    match result {
+        Ok(checked) => {
+            let components: Map<String, Value> =
+                checked.components.iter().map(|(part, value)| (part.to_string(), Value::from(value.as_str()))).collect();
+            json!({
+                "validator": name,
+                "input": input,
+                "valid": true,
+                "normalized": checked.normalized,
+                "components": components,
+                "errors": [],
+            })
+        }
+        Err(err) => json!({
+            "validator": name,
+            "input": input,
+            "valid": false,
+            "normalized": null,
+            "components": {},
+            "errors": [error_json(err)],
+        }),
+    }
+}
+
+/// Describes the rule `err` broke as a JSON object.
+fn error_json(err: &ValidationError) -> Value {
+    json!({
+        "rule": format!("{:?}", err.kind()),
+        "message": err.kind().to_string(),
+        "span": { "start": err.span().start, "end": err.span().end },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regular_expressions::identity::SsnValidator;
+
+    #[test]
+    fn test_result_json_valid_value() {
+        let result = check(&SsnValidator, "123 45 6788");
+        assert_eq!(
+            result_json("ssn", "123 45 6788", &result).to_string(),
+            r#"{"validator":"ssn","input":"123 45 6788","valid":true,"normalized":"123-45-6788","components":{"area":"123","group":"45","serial":"6788"},"errors":[]}"#
+        );
+    }
+
+    #[test]
+    fn test_result_json_invalid_value() {
+        let result = check(&SsnValidator, "666-12-3456");
+        let value = result_json("ssn", "666-12-3456", &result);
+        assert_eq!(value["valid"], false);
+        assert_eq!(value["normalized"], Value::Null);
+        assert_eq!(
+            value["errors"][0],
+            json!({
+                "rule": "Ssn(ForbiddenArea(666))",
+                "message": "SSN area number 666 is never issued",
+                "span": { "start": 0, "end": 3 },
+            })
+        );
+    }
+}
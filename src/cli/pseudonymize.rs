@@ -0,0 +1,65 @@
+//! The `pseudonymize` subcommand: replaces SSNs and phone numbers with keyed, reversible fakes.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, ValueEnum};
+use regular_expressions::contact::{format_phone_number, PhoneFormat};
+use regular_expressions::pseudonym::Pseudonymizer;
+
+use super::check::{self, CheckArgs};
+use super::output::{Checked, OutputFormat};
+
+/// The kinds of value that can be pseudonymized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum PseudonymKind {
+    Ssn,
+    Phone,
+}
+
+/// Arguments of the `pseudonymize` subcommand.
+#[derive(Debug, Args)]
+pub struct PseudonymizeArgs {
+    /// The kind of the values.
+    pub kind: PseudonymKind,
+
+    /// Read the secret key from this file. The same key is needed to reveal the originals.
+    #[arg(long, value_name = "PATH")]
+    pub key_file: PathBuf,
+
+    /// Recover the original values from pseudonyms instead.
+    #[arg(long)]
+    pub reveal: bool,
+
+    #[command(flatten)]
+    pub check: CheckArgs,
+}
+
+/// Runs the `pseudonymize` subcommand, printing the pseudonym (or original) of each value.
+///
+/// # Returns
+///
+/// * `ExitCode` - Success if every value is valid, 1 if any is invalid, and 2 if the key could
+///   not be read.
+pub fn run(args: PseudonymizeArgs, format: OutputFormat) -> ExitCode {
+    let key = match fs::read(&args.key_file) {
+        Ok(key) => key,
+        Err(err) => {
+            eprintln!("error: cannot read {}: {}", args.key_file.display(), err);
+            return ExitCode::from(2);
+        }
+    };
+    let pseudonymizer = Pseudonymizer::new(key.trim_ascii_end());
+    let reveal = args.reveal;
+    match args.kind {
+        PseudonymKind::Ssn => check::run_with("ssn", args.check, format, |value| {
+            let ssn = if reveal { pseudonymizer.reveal_ssn(value)? } else { pseudonymizer.pseudonymize_ssn(value)? };
+            Ok(Checked { normalized: ssn.to_string(), components: Vec::new() })
+        }),
+        PseudonymKind::Phone => check::run_with("phone", args.check, format, |value| {
+            let phone = if reveal { pseudonymizer.reveal_phone(value)? } else { pseudonymizer.pseudonymize_phone(value)? };
+            Ok(Checked { normalized: format_phone_number(&phone, PhoneFormat::National), components: Vec::new() })
+        }),
+    }
+}
@@ -0,0 +1,119 @@
+//! The `redact` subcommand: masks SSNs, phone numbers, emails and other fields in free text.
+
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Args;
+use regular_expressions::redact::{Mask, Redactor};
+use regular_expressions::scan::FieldKind;
+use serde_json::json;
+
+use super::output::OutputFormat;
+
+/// Arguments of the `redact` subcommand.
+#[derive(Debug, Args)]
+pub struct RedactArgs {
+    /// How to replace each value found.
+    #[arg(long, value_enum, default_value_t = Mask::Partial)]
+    pub mask: Mask,
+
+    /// Only redact this kind of field (ssn, phone, email, address, url, date, currency or zip).
+    /// May be repeated. Defaults to every kind.
+    #[arg(long = "kind", value_name = "KIND")]
+    pub kinds: Vec<FieldKind>,
+
+    /// Read the secret key for `--mask hash` from this file. Without a key, hashed SSNs and phone
+    /// numbers can be recovered by brute force.
+    #[arg(long, value_name = "PATH")]
+    pub hash_key_file: Option<PathBuf>,
+
+    /// The file to redact. Use `-` or leave out to read stdin. The redacted text is written to stdout.
+    pub input: Option<PathBuf>,
+}
+
+/// Runs the `redact` subcommand.
+///
+/// # Returns
+///
+/// * `ExitCode` - Success once the whole input is redacted, and 2 if it could not be read.
+pub fn run(args: RedactArgs, format: OutputFormat) -> ExitCode {
+    match run_with_files(&args, format) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Builds the redactor described by `args` and redacts the input to stdout.
+fn run_with_files(args: &RedactArgs, format: OutputFormat) -> Result<(), String> {
+    let mut redactor = Redactor::new(args.mask);
+    if !args.kinds.is_empty() {
+        for kind in FieldKind::ALL.into_iter().filter(|kind| !args.kinds.contains(kind)) {
+            redactor = redactor.without(kind);
+        }
+    }
+    if let Some(path) = &args.hash_key_file {
+        let key = fs::read(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+        redactor = redactor.with_hash_key(key.trim_ascii_end());
+    }
+
+    let input: Box<dyn BufRead> = match &args.input {
+        Some(path) if path != Path::new("-") => Box::new(BufReader::new(
+            File::open(path).map_err(|err| format!("cannot open {}: {}", path.display(), err))?,
+        )),
+        _ => Box::new(io::stdin().lock()),
+    };
+    redact_lines(&redactor, input, format, io::stdout().lock()).map_err(|err| err.to_string())
+}
+
+/// Redacts `input` to `output` one line at a time, so large logs are streamed rather than read whole.
+///
+/// # Description
+///
+/// * In text format the redacted lines are written as they are, keeping their line endings.
+/// * In JSON format each line is an object with the keys `line` (counting from 1) and `text`, the
+///   redacted line without its line ending.
+pub fn redact_lines<R: BufRead, W: Write>(redactor: &Redactor, mut input: R, format: OutputFormat, mut output: W) -> io::Result<()> {
+    let mut line = String::new();
+    let mut number = 0;
+    while input.read_line(&mut line)? > 0 {
+        number += 1;
+        match format {
+            OutputFormat::Text => output.write_all(redactor.redact(&line).as_bytes())?,
+            OutputFormat::Json => {
+                let text = line.strip_suffix('\n').map_or(line.as_str(), |text| text.strip_suffix('\r').unwrap_or(text));
+                writeln!(output, "{}", json!({ "line": number, "text": redactor.redact(text) }))?
+            }
+        }
+        line.clear();
+    }
+    output.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_lines() {
+        let input = "user jo@example.com\nSSN 123-45-6788\n\nno newline at end 206-301-0100";
+        let mut output = Vec::new();
+        redact_lines(&Redactor::new(Mask::Placeholder), input.as_bytes(), OutputFormat::Text, &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "user <EMAIL>\nSSN <SSN>\n\nno newline at end <PHONE>");
+    }
+
+    #[test]
+    fn test_redact_lines_json() {
+        let input = "user jo@example.com\r\nSSN 123-45-6788";
+        let mut output = Vec::new();
+        redact_lines(&Redactor::new(Mask::Placeholder), input.as_bytes(), OutputFormat::Json, &mut output).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "{\"line\":1,\"text\":\"user <EMAIL>\"}\n{\"line\":2,\"text\":\"SSN <SSN>\"}\n"
+        );
+    }
+}
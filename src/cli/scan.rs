@@ -0,0 +1,107 @@
+//! The `scan` subcommand: finds fields such as SSNs and email addresses in free text.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::Args;
+use regular_expressions::scan::{self, FieldKind, Finding};
+use serde_json::json;
+
+use super::output::OutputFormat;
+
+/// Arguments of the `scan` subcommand.
+#[derive(Debug, Args)]
+pub struct ScanArgs {
+    /// Only look for this kind of field (ssn, phone, email, address, url, date, currency or zip).
+    /// May be repeated. Defaults to every kind.
+    #[arg(long = "kind", value_name = "KIND")]
+    pub kinds: Vec<FieldKind>,
+
+    /// The file to scan. Use `-` or leave out to read stdin.
+    pub input: Option<PathBuf>,
+}
+
+/// Runs the `scan` subcommand, printing each field found.
+///
+/// # Returns
+///
+/// * `ExitCode` - Success if at least one field was found, 1 if none was, and 2 if the input
+///   could not be read.
+pub fn run(args: ScanArgs, format: OutputFormat) -> ExitCode {
+    let text = match read_input(args.input.as_deref()) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::from(2);
+        }
+    };
+    let kinds = if args.kinds.is_empty() { FieldKind::ALL.to_vec() } else { args.kinds };
+    let findings = scan::scan_for(&text, &kinds);
+    let _ = write_findings(&findings, format, io::stdout().lock());
+    if findings.is_empty() {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Reads the whole of `path`, or stdin if it is `None` or `-`.
+fn read_input(path: Option<&Path>) -> Result<String, String> {
+    match path {
+        Some(path) if path != Path::new("-") => {
+            fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))
+        }
+        _ => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).map_err(|err| format!("cannot read stdin: {}", err))?;
+            Ok(text)
+        }
+    }
+}
+
+/// Writes one line per finding to `out`.
+///
+/// # Description
+///
+/// * In text format each line is `START..END<TAB>KIND<TAB>VALUE`, with byte offsets into the input.
+/// * In JSON format each line is an object with the keys `kind`, `start`, `end` and `value`.
+pub fn write_findings<W: Write>(findings: &[Finding<'_>], format: OutputFormat, mut out: W) -> io::Result<()> {
+    for finding in findings {
+        let span = finding.span();
+        match format {
+            OutputFormat::Text => writeln!(out, "{}..{}\t{}\t{}", span.start, span.end, finding.kind(), finding.as_str())?,
+            OutputFormat::Json => writeln!(
+                out,
+                "{}",
+                json!({ "kind": finding.kind().name(), "start": span.start, "end": span.end, "value": finding.as_str() })
+            )?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "ticket from jo@example.com, SSN 123-45-6788";
+
+    fn written(format: OutputFormat) -> String {
+        let mut out = Vec::new();
+        write_findings(&scan::scan(TEXT), format, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_write_findings_text() {
+        assert_eq!(written(OutputFormat::Text), "12..26\temail\tjo@example.com\n32..43\tssn\t123-45-6788\n");
+    }
+
+    #[test]
+    fn test_write_findings_json() {
+        let first = written(OutputFormat::Json).lines().next().unwrap().to_string();
+        assert_eq!(first, r#"{"kind":"email","start":12,"end":26,"value":"jo@example.com"}"#);
+    }
+}
@@ -0,0 +1,1265 @@
+//! Contact details: phone numbers and email addresses.
+
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::LazyLock;
+
+use regex::Regex;
+use phonenumber::metadata::DATABASE;
+use phonenumber::{country, Mode, PhoneNumber, Type};
+
+use crate::error::{Span, ValidationError};
+use crate::idn;
+use crate::validator::{SeparatorMode, Validator};
+
+/// Matches a 10-digit US phone number with an optional leading country code of 1, capturing the
+/// area code, local prefix and local suffix.
+static PHONE_NUMBER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| {
+        Regex::new(concat!(
+            r"^\s*(?:\+?1[-\s]?)?(?P<open>\(?)(?P<area>[0-9]{3})(?P<close>\)?)(?P<first_separator>[-\s]?)",
+            r"(?P<exchange>[0-9]{3})(?P<second_separator>[-\s]?)(?P<line>[0-9]{4})\s*$",
+        ))
+        .unwrap()
+    });
+
+/// Matches a phone number followed by an extension such as `x123`, `ext. 45` or `#12`, capturing
+/// the number and the extension digits.
+static PHONE_EXTENSION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(?P<number>.+?)[\s,;]*(?:ext\.?|extension|x|#)\s*(?P<extension>[0-9]{1,6})\s*$").unwrap()
+});
+
+/// Matches an email address under the project rules, capturing the prefix and domain.
+static EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^(?P<prefix>[a-z0-9!#$%&'*+/=?^_`{|}~-]+(\.[a-z0-9!#$%&'*+/=?^_`{|}~-]+)*)(@)(?P<domain>[a-z0-9](?:[a-z0-9-]*[a-z0-9])?(\.[a-z0-9](?:[a-z0-9-]*[a-z0-9])?)+)$").unwrap());
+
+/// Matches two adjacent special characters in an email prefix, e.g. `.-` or `+_`.
+static CONSECUTIVE_SPECIAL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[^a-zA-Z0-9]{2}").unwrap());
+
+/// Matches an email address accepted by HTML5 `<input type="email">` fields.
+static HTML5_EMAIL_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[a-zA-Z0-9.!#$%&'*+/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$").unwrap()
+});
+
+/// Matches an RFC 5322 dot-atom local part, e.g. `first.last+tag`.
+static DOT_ATOM_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-zA-Z0-9!#$%&'*+/=?^_`{|}~-]+(?:\.[a-zA-Z0-9!#$%&'*+/=?^_`{|}~-]+)*$").unwrap());
+
+/// Matches an RFC 5322 quoted-string local part, e.g. `"john doe"` or `"a\"b"`.
+static QUOTED_STRING_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"^"(?:[\x20\x21\x23-\x5b\x5d-\x7e]|\\[\x20-\x7e])*"$"#).unwrap());
+
+/// Matches a domain name of dot separated labels of at most 63 letters, digits and inner hyphens.
+static HOSTNAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$").unwrap()
+});
+
+/// The longest local part (prefix) of an email address allowed by RFC 5321, in bytes.
+pub const MAX_EMAIL_PREFIX_LENGTH: usize = 64;
+
+/// The longest domain of an email address allowed by RFC 5321, in bytes.
+pub const MAX_EMAIL_DOMAIN_LENGTH: usize = 255;
+
+/// The longest label of a domain name allowed by RFC 1035, in bytes.
+const MAX_DOMAIN_LABEL_LENGTH: usize = 63;
+
+/// The rule a phone number broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhoneError {
+    /// The input is not a 10-digit number with an optional parenthesized area code.
+    Malformed,
+    /// The digits could not be parsed as a phone number.
+    Unparseable,
+    /// The number parses, but is not assigned to any valid area code and exchange.
+    InvalidNumber,
+    /// The number parses, but is not a valid number for its country calling code.
+    InvalidInternationalNumber,
+    /// The area code has an opening or a closing parenthesis but not both, which
+    /// [`SeparatorMode::Strict`] rejects.
+    UnbalancedParentheses,
+    /// The separators between the digit groups differ, e.g. `206-555 0100`, which
+    /// [`SeparatorMode::Strict`] rejects.
+    InconsistentSeparators,
+}
+
+impl fmt::Display for PhoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PhoneError::Malformed => write!(f, "phone number must be ten digits, e.g. (206) 555-0100"),
+            PhoneError::Unparseable => write!(f, "phone number could not be parsed"),
+            PhoneError::InvalidNumber => write!(f, "phone number is not a valid US number"),
+            PhoneError::InvalidInternationalNumber => write!(f, "phone number is not valid for its country calling code"),
+            PhoneError::UnbalancedParentheses => write!(f, "phone area code must have both parentheses or neither"),
+            PhoneError::InconsistentSeparators => {
+                write!(f, "phone number separators must be both dashes, both spaces or both absent, or follow a parenthesized area code")
+            }
+        }
+    }
+}
+
+/// The rule an email address broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmailError {
+    /// There is no @ separating the prefix and the domain.
+    MissingAt,
+    /// There is more than one @.
+    MultipleAt,
+    /// The prefix before the @ is empty.
+    EmptyPrefix,
+    /// The prefix contains a character that is not alphanumeric or an allowed special character.
+    InvalidPrefixCharacter(char),
+    /// A dot in the prefix is leading, trailing or next to another dot.
+    MisplacedDot,
+    /// The domain after the @ is empty.
+    EmptyDomain,
+    /// The domain has no dot separating a top-level domain.
+    MissingTopLevelDomain,
+    /// A domain label is empty, e.g. two consecutive dots.
+    EmptyDomainLabel,
+    /// A domain label starts or ends with a hyphen.
+    DomainLabelHyphen,
+    /// The domain contains a character other than a letter, digit, hyphen or dot.
+    InvalidDomainCharacter(char),
+    /// The prefix contains two special characters in a row, e.g. `a.-b` or `a+_b`.
+    ConsecutiveSpecialCharacters,
+    /// The prefix is longer than 64 bytes.
+    PrefixTooLong(usize),
+    /// The domain is longer than 255 bytes.
+    DomainTooLong(usize),
+    /// A domain label is longer than 63 bytes.
+    DomainLabelTooLong(usize),
+    /// A prefix starting with a double quote is not a valid quoted string.
+    InvalidQuotedPrefix,
+    /// A domain in square brackets is not an IPv4 or `IPv6:` address literal.
+    InvalidAddressLiteral,
+    /// A domain with non-ASCII or punycode labels is not a valid internationalized domain name.
+    InvalidInternationalDomain,
+    /// The address does not match the email format for another reason.
+    Malformed,
+}
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmailError::MissingAt => write!(f, "email must contain an @ between the prefix and domain"),
+            EmailError::MultipleAt => write!(f, "email must contain only one @"),
+            EmailError::EmptyPrefix => write!(f, "email prefix must not be empty"),
+            EmailError::InvalidPrefixCharacter(ch) => write!(f, "email prefix may not contain {:?}", ch),
+            EmailError::MisplacedDot => write!(f, "dots in the email prefix must not be leading, trailing or consecutive"),
+            EmailError::EmptyDomain => write!(f, "email domain must not be empty"),
+            EmailError::MissingTopLevelDomain => write!(f, "email domain must contain a dot"),
+            EmailError::EmptyDomainLabel => write!(f, "email domain labels must not be empty"),
+            EmailError::DomainLabelHyphen => write!(f, "email domain labels must not start or end with a hyphen"),
+            EmailError::InvalidDomainCharacter(ch) => write!(f, "email domain may not contain {:?}", ch),
+            EmailError::ConsecutiveSpecialCharacters => {
+                write!(f, "email prefix must not contain consecutive special characters")
+            }
+            EmailError::PrefixTooLong(len) => {
+                write!(f, "email prefix must be at most {} bytes, not {}", MAX_EMAIL_PREFIX_LENGTH, len)
+            }
+            EmailError::DomainTooLong(len) => {
+                write!(f, "email domain must be at most {} bytes, not {}", MAX_EMAIL_DOMAIN_LENGTH, len)
+            }
+            EmailError::DomainLabelTooLong(len) => {
+                write!(f, "email domain labels must be at most {} bytes, not {}", MAX_DOMAIN_LABEL_LENGTH, len)
+            }
+            EmailError::InvalidQuotedPrefix => write!(f, "quoted email prefix must be printable ASCII with \\ escaping \" and \\"),
+            EmailError::InvalidAddressLiteral => write!(f, "email domain literal must be an IPv4 address or IPv6: address"),
+            EmailError::InvalidInternationalDomain => write!(f, "email domain is not a valid internationalized domain name"),
+            EmailError::Malformed => write!(f, "email address is malformed"),
+        }
+    }
+}
+
+/// How strictly an email address is checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum EmailLevel {
+    /// The rules of HTML5 `<input type="email">` fields: dots may appear anywhere in the prefix
+    /// and the domain needs no top-level domain.
+    Html5,
+    /// The RFC 5322 address syntax, including quoted prefixes such as `"john doe"@example.com` and
+    /// IP address literal domains such as `jo@[192.0.2.1]`.
+    Rfc5322,
+    /// The project rules described on [`validate_email`].
+    #[default]
+    Project,
+}
+
+/// Validates a US phone number.
+///
+/// # Arguments
+///
+/// * `phone` - A string slice that holds the phone number.
+///
+/// # Rules
+///
+/// * Accepts US phone numbers with or without parentheses around the area code.
+/// * Accepts phone numbers with or without dashes or spaces as separators.
+/// * Accepts an optional leading country code of `1` or `+1`.
+/// * Accepts vanity letters, which are read as the digits they share a keypad key with, e.g.
+///   `1-800-FLOWERS` is `1-800-356-9377`.
+/// * Accepts a trailing extension of up to six digits, e.g. `x123`, `ext. 45` or `#12`.
+/// * Only allows valid area codes.
+///
+/// # Returns
+///
+/// * `Result<PhoneNumber, ValidationError>` - Returns the parsed `PhoneNumber`, including any
+///   extension, if the phone number is valid, or the broken rule (a [`PhoneError`]) otherwise.
+pub fn validate_phone_number(phone: &str) -> Result<PhoneNumber, ValidationError> {
+    validate_phone_number_with(phone, SeparatorMode::Lenient)
+}
+
+/// Validates a US phone number, checking its separators as strictly as `mode` says.
+///
+/// # Arguments
+///
+/// * `phone` - A string slice that holds the phone number.
+/// * `mode` - How strictly to check the separators and parentheses.
+///
+/// # Rules
+///
+/// * Every rule of [`validate_phone_number`].
+/// * In [`SeparatorMode::Strict`], an area code must have both parentheses or neither.
+/// * In [`SeparatorMode::Strict`], the two separators between the area code, exchange and line
+///   number must be both dashes, both spaces or both absent. After a parenthesized area code
+///   only a space or nothing may follow, so `(206) 555-0100` is valid but `(206)-555-0100` is not.
+/// * A separator left out between two vanity letters, as in `1-800-FLOWERS`, and the separator
+///   after the country code are not checked.
+///
+/// # Returns
+///
+/// * `Result<PhoneNumber, ValidationError>` - Returns the parsed `PhoneNumber`, including any
+///   extension, if the phone number is valid, or the broken rule (a [`PhoneError`]) and the span
+///   of the offending characters otherwise.
+pub fn validate_phone_number_with(phone: &str, mode: SeparatorMode) -> Result<PhoneNumber, ValidationError> {
+    // Try the input as a number followed by an extension first, falling back to the whole input
+    // so that vanity numbers ending in an X (such as 1-800-MAX-1234) are not split.
+    let with_extension = PHONE_EXTENSION_REGEX
+        .captures(phone)
+        .and_then(|captures| Some((nanp_digits(&captures["number"], mode)?, Some(captures["extension"].to_string()))));
+    let Some((digits, extension)) = with_extension.or_else(|| Some((nanp_digits(phone, mode)?, None))) else {
+        return Err(ValidationError::new(PhoneError::Malformed, Span::whole(phone)));
+    };
+    let digits = digits?;
+
+    // Build an RFC 3966 URI from the digit strings, so leading zeros in any part are preserved.
+    let mut uri = format!("tel:+1-{}", digits);
+    if let Some(extension) = extension {
+        uri.push_str(";ext=");
+        uri.push_str(&extension);
+    }
+
+    // Use the 'phonenumber' crate to parse and validate the formatted phone number.
+    let parsed = phonenumber::parse(Some(country::US), &uri)
+        .map_err(|_| ValidationError::new(PhoneError::Unparseable, Span::whole(phone)))?;
+    if !parsed.is_valid() {
+        return Err(ValidationError::new(PhoneError::InvalidNumber, Span::whole(phone)));
+    }
+    Ok(parsed)
+}
+
+/// Returns the ten digits of the US number `number`, after reading vanity letters as digits.
+///
+/// # Returns
+///
+/// * `Option<Result<String, ValidationError>>` - Returns `None` if `number` is not a US number,
+///   and otherwise its digits, or the separator rule of `mode` it broke.
+fn nanp_digits(number: &str, mode: SeparatorMode) -> Option<Result<String, ValidationError>> {
+    // Letters map to ASCII digits one for one, so spans in the mapped number are spans in `number`.
+    let mapped: String = number.chars().map(keypad_digit).collect();
+    let captures = PHONE_NUMBER_REGEX.captures(&mapped)?;
+    if mode == SeparatorMode::Strict {
+        let (open, close) = (captures.name("open").unwrap(), captures.name("close").unwrap());
+        if open.is_empty() != close.is_empty() {
+            let parenthesis = if open.is_empty() { close } else { open };
+            return Some(Err(ValidationError::new(PhoneError::UnbalancedParentheses, parenthesis)));
+        }
+        let first = captures.name("first_separator").unwrap();
+        let second = captures.name("second_separator").unwrap();
+        // A separator is naturally left out inside a vanity word such as FLOWERS.
+        let in_word = |separator: regex::Match| {
+            separator.is_empty()
+                && number[..separator.start()].ends_with(|ch: char| ch.is_ascii_alphabetic())
+                && number[separator.end()..].starts_with(|ch: char| ch.is_ascii_alphabetic())
+        };
+        let consistent = if open.is_empty() {
+            first.as_str() == second.as_str() || in_word(first) || in_word(second)
+        } else {
+            matches!(first.as_str(), "" | " ")
+        };
+        if !consistent {
+            return Some(Err(ValidationError::new(PhoneError::InconsistentSeparators, first.start()..second.end())));
+        }
+    }
+    Some(Ok(format!("{}{}{}", &captures["area"], &captures["exchange"], &captures["line"])))
+}
+
+/// Returns the digit that shares a telephone keypad key with the letter `ch`, or `ch` if it is not a letter.
+fn keypad_digit(ch: char) -> char {
+    match ch.to_ascii_uppercase() {
+        'A'..='C' => '2',
+        'D'..='F' => '3',
+        'G'..='I' => '4',
+        'J'..='L' => '5',
+        'M'..='O' => '6',
+        'P'..='S' => '7',
+        'T'..='V' => '8',
+        'W'..='Z' => '9',
+        _ => ch,
+    }
+}
+
+/// Output formats for valid phone numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum PhoneFormat {
+    /// `+12065550100`
+    E164,
+    /// `(206) 555-0100`
+    National,
+    /// `+1 206-555-0100`
+    International,
+    /// `tel:+1-206-555-0100`
+    Rfc3966,
+}
+
+impl From<PhoneFormat> for Mode {
+    fn from(format: PhoneFormat) -> Mode {
+        match format {
+            PhoneFormat::E164 => Mode::E164,
+            PhoneFormat::National => Mode::National,
+            PhoneFormat::International => Mode::International,
+            PhoneFormat::Rfc3966 => Mode::Rfc3966,
+        }
+    }
+}
+
+/// Formats `phone_number` in `format`.
+///
+/// # Description
+///
+/// * The national and international formats write an extension as ` ext. 123`, and RFC 3966 as
+///   `;ext=123`. E.164 has no room for an extension and leaves it out.
+pub fn format_phone_number(phone_number: &PhoneNumber, format: PhoneFormat) -> String {
+    phone_number.format().mode(format.into()).to_string()
+}
+
+/// A valid phone number from any country, together with the country and kind of line it belongs to.
+#[derive(Debug, Clone)]
+pub struct InternationalPhoneNumber {
+    number: PhoneNumber,
+    country: Option<country::Id>,
+    number_type: Type,
+}
+
+impl InternationalPhoneNumber {
+    /// Returns the parsed number, e.g. for formatting with [`Mode::E164`].
+    pub fn number(&self) -> &PhoneNumber {
+        &self.number
+    }
+
+    /// Returns the country the number is assigned to, or `None` for non-geographic numbers.
+    pub fn country(&self) -> Option<country::Id> {
+        self.country
+    }
+
+    /// Returns the kind of line, e.g. [`Type::Mobile`], [`Type::FixedLine`] or [`Type::TollFree`].
+    pub fn number_type(&self) -> Type {
+        self.number_type
+    }
+}
+
+/// Validates a phone number from any country.
+///
+/// # Arguments
+///
+/// * `phone` - A string slice that holds the phone number.
+/// * `default_region` - The country assumed for numbers written without a `+` country calling code.
+///
+/// # Rules
+///
+/// * Accepts E.164 numbers (`+442079460018`), international numbers with separators
+///   (`+44 20 7946 0018`), RFC 3966 URIs (`tel:+44-20-7946-0018`), and national numbers of the
+///   default region (`020 7946 0018` with a default region of GB).
+/// * The number must be valid for its country according to the `phonenumber` metadata.
+///
+/// # Returns
+///
+/// * `Result<InternationalPhoneNumber, ValidationError>` - Returns the number with its detected
+///   country and number type if it is valid, or the broken rule (a [`PhoneError`]) otherwise.
+pub fn validate_international_phone_number(
+    phone: &str,
+    default_region: country::Id,
+) -> Result<InternationalPhoneNumber, ValidationError> {
+    let number = phonenumber::parse(Some(default_region), phone.trim())
+        .map_err(|_| ValidationError::new(PhoneError::Unparseable, Span::whole(phone)))?;
+    if !number.is_valid() {
+        return Err(ValidationError::new(PhoneError::InvalidInternationalNumber, Span::whole(phone)));
+    }
+    let country = number.country().id();
+    let number_type = number.number_type(&DATABASE);
+    Ok(InternationalPhoneNumber { number, country, number_type })
+}
+
+/// Returns the kebab-case name of a phone number type, e.g. `"toll-free"`.
+pub fn number_type_name(number_type: Type) -> &'static str {
+    match number_type {
+        Type::FixedLine => "fixed-line",
+        Type::Mobile => "mobile",
+        Type::FixedLineOrMobile => "fixed-line-or-mobile",
+        Type::TollFree => "toll-free",
+        Type::PremiumRate => "premium-rate",
+        Type::SharedCost => "shared-cost",
+        Type::PersonalNumber => "personal-number",
+        Type::Voip => "voip",
+        Type::Pager => "pager",
+        Type::Uan => "uan",
+        Type::Emergency => "emergency",
+        Type::Voicemail => "voicemail",
+        Type::ShortCode => "short-code",
+        Type::StandardRate => "standard-rate",
+        Type::Carrier => "carrier",
+        Type::NoInternational => "no-international",
+        Type::Unknown => "unknown",
+    }
+}
+
+/// Validates an email address according to the specified rules.
+///
+/// # Arguments
+///
+/// * `email` - A string slice that holds the email address to be validated.
+///
+/// # Rules
+///
+/// * Allowed special characters are !#$ %&'*/=? ^_+-`{|}~
+/// * No consecutive special characters.
+/// * Alphanumeric characters ignoring case.
+/// * Only one @ symbol for separating the prefix and domain.
+/// * Only a single . is allowed as a special character in the domain.
+/// * At most 64 bytes before the @ and 255 bytes after it.
+///
+/// # Returns
+///
+/// * `Result<(), ValidationError>` - Returns `Ok(())` if the email address is valid, or the
+///   broken rule (an [`EmailError`]) otherwise.
+pub fn validate_email(email: &str) -> Result<(), ValidationError> {
+    validate_email_with(email, EmailLevel::Project)
+}
+
+/// Validates an email address at the given strictness `level`.
+///
+/// # Description
+///
+/// * [`EmailLevel::Project`] applies the rules of [`validate_email`].
+/// * [`EmailLevel::Html5`] accepts what browsers accept in `<input type="email">` fields.
+/// * [`EmailLevel::Rfc5322`] accepts the RFC 5322 `addr-spec` syntax without comments or folding
+///   white space: a dot-atom or quoted-string prefix and a domain name or address literal.
+/// * Every level limits the prefix to 64 bytes and the domain to 255 bytes, as RFC 5321 does.
+///
+/// # Returns
+///
+/// * `Result<(), ValidationError>` - Returns `Ok(())` if the email address is valid, or the
+///   broken rule (an [`EmailError`]) otherwise.
+pub fn validate_email_with(email: &str, level: EmailLevel) -> Result<(), ValidationError> {
+    let error = |kind: EmailError, start: usize, end: usize| Err(ValidationError::new(kind, Span::new(start, end)));
+
+    // A quoted prefix may itself contain an @, so only the last one separates the domain.
+    let at = match level {
+        EmailLevel::Rfc5322 => email.rfind('@'),
+        _ => email.find('@'),
+    };
+    let Some(at) = at else {
+        return error(EmailError::MissingAt, 0, email.len());
+    };
+    if at > MAX_EMAIL_PREFIX_LENGTH {
+        return error(EmailError::PrefixTooLong(at), 0, at);
+    }
+    let domain_len = email.len() - at - 1;
+    if domain_len > MAX_EMAIL_DOMAIN_LENGTH {
+        return error(EmailError::DomainTooLong(domain_len), at + 1, email.len());
+    }
+
+    let (prefix, domain) = (&email[..at], &email[at + 1..]);
+    let matched = match level {
+        EmailLevel::Project => EMAIL_REGEX.is_match(email) && !CONSECUTIVE_SPECIAL_REGEX.is_match(prefix),
+        EmailLevel::Html5 => HTML5_EMAIL_REGEX.is_match(email),
+        EmailLevel::Rfc5322 => {
+            (DOT_ATOM_REGEX.is_match(prefix) || QUOTED_STRING_REGEX.is_match(prefix))
+                && (HOSTNAME_REGEX.is_match(domain) || is_address_literal(domain))
+        }
+    };
+    if matched {
+        return Ok(());
+    }
+    Err(diagnose_email(email, at, level))
+}
+
+/// Returns whether `domain` is an address literal such as `[192.0.2.1]` or `[IPv6:2001:db8::1]`.
+fn is_address_literal(domain: &str) -> bool {
+    let Some(literal) = domain.strip_prefix('[').and_then(|domain| domain.strip_suffix(']')) else {
+        return false;
+    };
+    // The "IPv6:" tag is case-insensitive, like every literal string in the RFC 5321 grammar.
+    match literal.get(..5).filter(|tag| tag.eq_ignore_ascii_case("IPv6:")) {
+        Some(_) => literal[5..].parse::<Ipv6Addr>().is_ok(),
+        None => literal.parse::<Ipv4Addr>().is_ok(),
+    }
+}
+
+/// A valid email address whose prefix and domain may contain Unicode characters.
+///
+/// # Description
+///
+/// * Produced by [`validate_international_email`].
+/// * The domain is kept in both its Unicode form (`bücher.de`) and its ASCII form
+///   (`xn--bcher-kva.de`), lowercased.
+/// * Displays in its Unicode form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InternationalEmail {
+    prefix: String,
+    unicode_domain: String,
+    ascii_domain: String,
+}
+
+impl InternationalEmail {
+    /// Returns the prefix (local part) before the @, as written.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the domain in its Unicode form, e.g. `bücher.de`.
+    pub fn unicode_domain(&self) -> &str {
+        &self.unicode_domain
+    }
+
+    /// Returns the domain in its ASCII form, e.g. `xn--bcher-kva.de`.
+    pub fn ascii_domain(&self) -> &str {
+        &self.ascii_domain
+    }
+
+    /// Returns the address with its domain in Unicode form, e.g. `josé@bücher.de`.
+    pub fn to_unicode(&self) -> String {
+        format!("{}@{}", self.prefix, self.unicode_domain)
+    }
+
+    /// Returns the address with its domain in ASCII form, e.g. `josé@xn--bcher-kva.de`.
+    ///
+    /// A non-ASCII prefix has no ASCII form, so the address is only fully ASCII if
+    /// [`InternationalEmail::requires_smtputf8`] is false.
+    pub fn to_ascii(&self) -> String {
+        format!("{}@{}", self.prefix, self.ascii_domain)
+    }
+
+    /// Returns whether the prefix contains non-ASCII characters, so the address can only be
+    /// delivered by mail servers supporting the SMTPUTF8 extension of RFC 6531.
+    pub fn requires_smtputf8(&self) -> bool {
+        !self.prefix.is_ascii()
+    }
+}
+
+impl fmt::Display for InternationalEmail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.prefix, self.unicode_domain)
+    }
+}
+
+/// Validates an internationalized email address, such as `josé@bücher.de`, at the given
+/// strictness `level`.
+///
+/// # Description
+///
+/// * The prefix may contain any non-ASCII character wherever `level` allows a letter, as RFC 6531
+///   allows; its 64 byte limit counts the bytes of its UTF-8 encoding.
+/// * A domain with non-ASCII or punycode labels must be a valid internationalized domain name;
+///   see [`idn`]. The rules of `level` are then applied to its ASCII form.
+///
+/// # Returns
+///
+/// * `Result<InternationalEmail, ValidationError>` - Returns the address with both forms of its
+///   domain if it is valid, or the broken rule (an [`EmailError`]) otherwise.
+pub fn validate_international_email(email: &str, level: EmailLevel) -> Result<InternationalEmail, ValidationError> {
+    let at = match level {
+        EmailLevel::Rfc5322 => email.rfind('@'),
+        _ => email.find('@'),
+    };
+    let Some(at) = at else {
+        return Err(ValidationError::new(EmailError::MissingAt, Span::whole(email)));
+    };
+    let (prefix, domain) = (&email[..at], &email[at + 1..]);
+    let domain_span = Span::new(at + 1, email.len());
+
+    let international = idn::is_internationalized(domain) && !domain.starts_with('[');
+    let (unicode_domain, ascii_domain) = if international {
+        let invalid = || ValidationError::new(EmailError::InvalidInternationalDomain, domain_span);
+        (idn::domain_to_unicode(domain).ok_or_else(invalid)?, idn::domain_to_ascii(domain).ok_or_else(invalid)?)
+    } else {
+        (domain.to_ascii_lowercase(), domain.to_ascii_lowercase())
+    };
+
+    // Check the prefix as if each non-ASCII character were ASCII letters of the same length, so the
+    // spans of errors in the prefix still point into `email`.
+    let ascii_prefix: String =
+        prefix.chars().map(|ch| if ch.is_ascii() { ch.to_string() } else { "a".repeat(ch.len_utf8()) }).collect();
+    validate_email_with(&format!("{}@{}", ascii_prefix, ascii_domain), level).map_err(|err| {
+        if international && err.span().end > at {
+            ValidationError::new(err.kind().clone(), domain_span)
+        } else {
+            err
+        }
+    })?;
+    Ok(InternationalEmail { prefix: prefix.to_string(), unicode_domain, ascii_domain })
+}
+
+/// Explains why `email`, with its prefix and domain separated by the @ at byte `at`, does not
+/// match the email format of `level`.
+fn diagnose_email(email: &str, at: usize, level: EmailLevel) -> ValidationError {
+    let error = |kind: EmailError, start: usize, end: usize| ValidationError::new(kind, Span::new(start, end));
+
+    if level != EmailLevel::Rfc5322 {
+        if let Some(second) = email[at + 1..].find('@') {
+            let second = at + 1 + second;
+            return error(EmailError::MultipleAt, second, second + 1);
+        }
+    }
+
+    let prefix = &email[..at];
+    if prefix.is_empty() {
+        return error(EmailError::EmptyPrefix, 0, at);
+    }
+    if level == EmailLevel::Rfc5322 && prefix.starts_with('"') {
+        if !QUOTED_STRING_REGEX.is_match(prefix) {
+            return error(EmailError::InvalidQuotedPrefix, 0, at);
+        }
+    } else {
+        if let Some((i, ch)) = prefix.char_indices().find(|(_, ch)| !(ch.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-.".contains(*ch))) {
+            return error(EmailError::InvalidPrefixCharacter(ch), i, i + ch.len_utf8());
+        }
+        if level != EmailLevel::Html5 && (prefix.starts_with('.') || prefix.ends_with('.') || prefix.contains("..")) {
+            let i = if prefix.starts_with('.') { 0 } else { prefix.find("..").unwrap_or(prefix.len() - 1) };
+            return error(EmailError::MisplacedDot, i, i + 1);
+        }
+        if level == EmailLevel::Project {
+            if let Some(run) = CONSECUTIVE_SPECIAL_REGEX.find(prefix) {
+                return error(EmailError::ConsecutiveSpecialCharacters, run.start(), run.end());
+            }
+        }
+    }
+
+    let domain_start = at + 1;
+    let domain = &email[domain_start..];
+    if domain.is_empty() {
+        return error(EmailError::EmptyDomain, domain_start, email.len());
+    }
+    if level == EmailLevel::Rfc5322 && domain.starts_with('[') {
+        return error(EmailError::InvalidAddressLiteral, domain_start, email.len());
+    }
+    if let Some((i, ch)) = domain.char_indices().find(|(_, ch)| !(ch.is_ascii_alphanumeric() || *ch == '-' || *ch == '.')) {
+        let i = domain_start + i;
+        return error(EmailError::InvalidDomainCharacter(ch), i, i + ch.len_utf8());
+    }
+    if level == EmailLevel::Project && !domain.contains('.') {
+        return error(EmailError::MissingTopLevelDomain, domain_start, email.len());
+    }
+    let mut offset = domain_start;
+    for label in domain.split('.') {
+        if label.is_empty() {
+            return error(EmailError::EmptyDomainLabel, offset, offset);
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return error(EmailError::DomainLabelHyphen, offset, offset + label.len());
+        }
+        if level != EmailLevel::Project && label.len() > MAX_DOMAIN_LABEL_LENGTH {
+            return error(EmailError::DomainLabelTooLong(label.len()), offset, offset + label.len());
+        }
+        offset += label.len() + 1;
+    }
+
+    error(EmailError::Malformed, 0, email.len())
+}
+
+/// The [`Validator`] for US phone numbers; see [`validate_phone_number`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PhoneValidator;
+
+impl Validator for PhoneValidator {
+    fn name(&self) -> &'static str {
+        "phone"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "Ten digits, with or without parentheses around the area code and a leading 1 or +1.",
+            "Dashes or spaces may separate the area code, prefix and line number.",
+            "Letters are read as the digits on the same keypad key, e.g. 1-800-FLOWERS.",
+            "An extension of up to six digits may follow, e.g. x123 or ext. 45.",
+            "The area code and exchange must be assigned to a valid US number.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_phone_number(input).map(|_| ())
+    }
+
+    /// Normalizes the number to the national format, e.g. `(206) 555-0100 ext. 12`.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        let phone_number = validate_phone_number(input)?;
+        Ok(format_phone_number(&phone_number, PhoneFormat::National))
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        let phone_number = validate_phone_number(input)?;
+        let mut components = vec![
+            ("national", format_phone_number(&phone_number, PhoneFormat::National)),
+            ("e164", format_phone_number(&phone_number, PhoneFormat::E164)),
+            ("international", format_phone_number(&phone_number, PhoneFormat::International)),
+        ];
+        if let Some(extension) = phone_number.extension() {
+            components.push(("extension", extension.to_string()));
+        }
+        Ok(components)
+    }
+}
+
+/// The [`Validator`] for phone numbers from any country; see [`validate_international_phone_number`].
+#[derive(Debug, Clone, Copy)]
+pub struct InternationalPhoneValidator {
+    default_region: country::Id,
+}
+
+impl InternationalPhoneValidator {
+    /// Creates a validator that reads numbers without a country calling code as numbers of `default_region`.
+    pub fn new(default_region: country::Id) -> InternationalPhoneValidator {
+        InternationalPhoneValidator { default_region }
+    }
+
+    /// Returns the country assumed for numbers without a country calling code.
+    pub fn default_region(&self) -> country::Id {
+        self.default_region
+    }
+}
+
+/// Defaults to reading national numbers as US numbers.
+impl Default for InternationalPhoneValidator {
+    fn default() -> InternationalPhoneValidator {
+        InternationalPhoneValidator::new(country::US)
+    }
+}
+
+impl Validator for InternationalPhoneValidator {
+    fn name(&self) -> &'static str {
+        "international-phone"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "A number with a + country calling code, or a national number of the default region.",
+            "Spaces, dashes, dots and parentheses may separate the digits.",
+            "The number must be valid for its country.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_international_phone_number(input, self.default_region).map(|_| ())
+    }
+
+    /// Normalizes the number to E.164, e.g. `+442079460018`.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        let phone = validate_international_phone_number(input, self.default_region)?;
+        Ok(phone.number().format().mode(Mode::E164).to_string())
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        let phone = validate_international_phone_number(input, self.default_region)?;
+        let format = |mode: Mode| phone.number().format().mode(mode).to_string();
+        Ok(vec![
+            ("country", phone.country().map_or(String::new(), |id| id.as_ref().to_string())),
+            ("country-code", phone.number().code().value().to_string()),
+            ("type", number_type_name(phone.number_type()).to_string()),
+            ("national", format(Mode::National)),
+            ("e164", format(Mode::E164)),
+            ("international", format(Mode::International)),
+        ])
+    }
+}
+
+/// The [`Validator`] for email addresses; see [`validate_email_with`] and
+/// [`validate_international_email`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmailValidator {
+    level: EmailLevel,
+    unicode: bool,
+}
+
+impl EmailValidator {
+    /// Creates a validator that checks ASCII addresses at the given strictness `level`.
+    pub fn new(level: EmailLevel) -> EmailValidator {
+        EmailValidator { level, unicode: false }
+    }
+
+    /// Sets whether internationalized addresses such as `josé@bücher.de` are accepted. They are
+    /// normalized to the Unicode form of their domain.
+    pub fn with_unicode(mut self, unicode: bool) -> EmailValidator {
+        self.unicode = unicode;
+        self
+    }
+
+    /// Returns the strictness level addresses are checked at.
+    pub fn level(&self) -> EmailLevel {
+        self.level
+    }
+
+    /// Returns whether internationalized addresses are accepted.
+    pub fn unicode(&self) -> bool {
+        self.unicode
+    }
+}
+
+impl Validator for EmailValidator {
+    fn name(&self) -> &'static str {
+        "email"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        match self.level {
+            EmailLevel::Html5 => &[
+                "A prefix of letters, digits, dots and the special characters !#$%&'*/=?^_+-`{|}~.",
+                "Only one @ symbol, separating the prefix and domain.",
+                "A domain of dot separated labels of up to 63 letters, digits and inner hyphens.",
+                "At most 64 characters before the @ and 255 after it.",
+            ],
+            EmailLevel::Rfc5322 => &[
+                "A prefix of dot separated atoms of letters, digits and the special characters !#$%&'*/=?^_+-`{|}~,",
+                "or a double quoted string of printable characters, with \\ escaping \" and \\.",
+                "An @ symbol, separating the prefix and domain.",
+                "A domain of dot separated labels of up to 63 letters, digits and inner hyphens,",
+                "or an address literal such as [192.0.2.1] or [IPv6:2001:db8::1].",
+                "At most 64 characters before the @ and 255 after it.",
+            ],
+            EmailLevel::Project => &[
+                "A prefix of letters, digits and the special characters !#$%&'*/=?^_+-`{|}~.",
+                "Dots in the prefix must not be leading, trailing or consecutive.",
+                "No two special characters in a row in the prefix.",
+                "Only one @ symbol, separating the prefix and domain.",
+                "A domain of dot separated labels of letters, digits and inner hyphens.",
+                "At most 64 characters before the @ and 255 after it.",
+            ],
+        }
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        if self.unicode {
+            return validate_international_email(input, self.level).map(|_| ());
+        }
+        validate_email_with(input, self.level)
+    }
+
+    /// Lowercases the domain; the prefix is case-sensitive and kept as written.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        if self.unicode {
+            return validate_international_email(input, self.level).map(|email| email.to_unicode());
+        }
+        validate_email_with(input, self.level)?;
+        let (prefix, domain) = input.rsplit_once('@').unwrap();
+        Ok(format!("{}@{}", prefix, domain.to_ascii_lowercase()))
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        if self.unicode {
+            let email = validate_international_email(input, self.level)?;
+            return Ok(vec![
+                ("prefix", email.prefix().to_string()),
+                ("domain", email.unicode_domain().to_string()),
+                ("ascii-domain", email.ascii_domain().to_string()),
+            ]);
+        }
+        validate_email_with(input, self.level)?;
+        let (prefix, domain) = input.rsplit_once('@').unwrap();
+        Ok(vec![("prefix", prefix.to_string()), ("domain", domain.to_string())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_validate_phone_number() {
+        let phone_number = validate_phone_number("2063311383").expect("number should be valid");
+        assert!(phone_number.is_valid());
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_dashes() {
+        let phone_number = validate_phone_number("509-331-1383").expect("number should be valid");
+        assert!(phone_number.is_valid());
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_spaces() {
+        let phone_number = validate_phone_number("206 301 1473").expect("number should be valid");
+        assert!(phone_number.is_valid());
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_parentheses() {
+        let phone_number = validate_phone_number("(206)3011473").expect("number should be valid");
+        assert!(phone_number.is_valid());
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_dashes_and_parentheses() {
+        let phone_number = validate_phone_number("(206) 301-1473").expect("number should be valid");
+        assert!(phone_number.is_valid());
+    }
+
+    #[test]
+    fn test_invalid_validate_phone_number() {
+        let err = validate_phone_number("1233011473").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_invalid_validate_phone_number_with_dashes() {
+        let err = validate_phone_number("101-015-9846").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_invalid_validate_phone_number_with_spaces() {
+        let err = validate_phone_number("101 015 9846").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_invalid_validate_phone_number_with_parentheses() {
+        let err = validate_phone_number("(101)0159846").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_invalid_validate_phone_number_with_dashes_and_parentheses() {
+        let err = validate_phone_number("(101) 015-9846").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::InvalidNumber));
+    }
+
+    #[test]
+    fn test_validate_email_with_valid_email() {
+        assert!(validate_email("notafed@fbi.gov").is_ok());
+    }
+
+    #[test]
+    fn test_validate_email_with_valid_username_dashes() {
+        assert!(validate_email("not-a-fed@fbi.gov").is_ok());
+    }
+
+    #[test]
+    fn test_validate_email_with_valid_username_underscores() {
+        assert!(validate_email("not_a_fed@fbi.gov").is_ok());
+    }
+
+    #[test]
+    fn test_validate_email_with_valid_domain() {
+        assert!(validate_email("definitelyNotAFed@fbi.gov").is_ok());
+    }
+
+    #[test]
+    fn test_validate_email_with_valid_domain_dash() {
+        assert!(validate_email("definitelynotaFed@fed-fbi.gov").is_ok());
+    }
+
+    #[test]
+    fn test_validate_email_with_invalid_domain() {
+        assert!(validate_email("notafed@fb..i.gov").is_err());
+    }
+
+    #[test]
+    fn test_validate_email_with_invalid_domain_2() {
+        assert!(validate_email("notafed@fbi..gov").is_err());
+    }
+
+    #[test]
+    fn test_validate_email_with_invalid_username() {
+        assert!(validate_email("notafed-@fbi.gov").is_ok());
+    }
+
+    #[test]
+    fn test_validate_email_with_invalid_username_2() {
+        assert!(validate_email("not...fed@domain.gov").is_err());
+    }
+
+    #[test]
+    fn test_validate_email_with_invalid_symbol() {
+        assert!(validate_email("notafbi.gov").is_err());
+    }
+
+    #[test]
+    fn test_validate_phone_number_reports_malformed() {
+        let err = validate_phone_number("206-555-010").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::Malformed));
+    }
+
+    #[test]
+    fn test_validate_email_reports_missing_at() {
+        let err = validate_email("notafbi.gov").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::MissingAt));
+    }
+
+    #[test]
+    fn test_validate_email_reports_empty_domain_label() {
+        let err = validate_email("notafed@fbi..gov").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::EmptyDomainLabel));
+        assert_eq!(err.span(), Span::new(12, 12));
+    }
+
+    #[test]
+    fn test_validate_email_reports_misplaced_dot() {
+        let err = validate_email("not...fed@domain.gov").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::MisplacedDot));
+        assert_eq!(err.span(), Span::new(3, 4));
+    }
+
+    #[test]
+    fn test_validate_email_reports_invalid_prefix_character() {
+        let err = validate_email("not(a)fed@fbi.gov").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::InvalidPrefixCharacter('(')));
+        assert_eq!(err.span(), Span::new(3, 4));
+    }
+
+    #[test]
+    fn test_validate_email_reports_consecutive_special_characters() {
+        let err = validate_email("not.-a-fed@fbi.gov").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::ConsecutiveSpecialCharacters));
+        assert_eq!(err.span(), Span::new(3, 5));
+        assert!(validate_email("not-a-fed-@fbi.gov").is_ok());
+    }
+
+    #[test]
+    fn test_validate_email_length_limits() {
+        let domain = format!("{}.gov", "a".repeat(251));
+        assert!(validate_email(&format!("{}@fbi.gov", "a".repeat(64))).is_ok());
+        assert!(validate_email(&format!("jo@{}", domain)).is_ok());
+        for level in [EmailLevel::Html5, EmailLevel::Rfc5322, EmailLevel::Project] {
+            let err = validate_email_with(&format!("{}@fbi.gov", "a".repeat(65)), level).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Email(EmailError::PrefixTooLong(65)));
+            let err = validate_email_with(&format!("jo@a{}", domain), level).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Email(EmailError::DomainTooLong(256)));
+            assert_eq!(err.span(), Span::new(3, 259));
+        }
+    }
+
+    #[test]
+    fn test_validate_email_html5_level() {
+        for email in [".not..a.fed.@fbi.gov", "notafed@localhost", "not.-a-fed@fbi.gov"] {
+            assert!(validate_email_with(email, EmailLevel::Html5).is_ok(), "{}", email);
+        }
+        let err = validate_email_with(&format!("jo@{}.gov", "a".repeat(64)), EmailLevel::Html5).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::DomainLabelTooLong(64)));
+        assert!(validate_email_with("\"jo\"@fbi.gov", EmailLevel::Html5).is_err());
+    }
+
+    #[test]
+    fn test_validate_email_rfc5322_level() {
+        for email in ["\"john doe\"@fbi.gov", "\"jo@home\"@fbi.gov", "\"a\\\"b\"@fbi.gov", "jo@[192.0.2.1]", "jo@[IPv6:2001:db8::1]", "jo@localhost"] {
+            assert!(validate_email_with(email, EmailLevel::Rfc5322).is_ok(), "{}", email);
+            assert!(validate_email(email).is_err(), "{}", email);
+        }
+        let err = validate_email_with("\"jo\"hn\"@fbi.gov", EmailLevel::Rfc5322).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::InvalidQuotedPrefix));
+        let err = validate_email_with("jo@[192.0.2.256]", EmailLevel::Rfc5322).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::InvalidAddressLiteral));
+        assert_eq!(err.span(), Span::new(3, 16));
+        assert!(validate_email_with(".jo@fbi.gov", EmailLevel::Rfc5322).is_err());
+    }
+
+    #[test]
+    fn test_email_validator_normalizes_address_literal() {
+        let validator = EmailValidator::new(EmailLevel::Rfc5322);
+        assert_eq!(validator.normalize("\"Jo@Home\"@[IPv6:2001:DB8::1]").unwrap(), "\"Jo@Home\"@[ipv6:2001:db8::1]");
+        assert!(validator.validate("jo@[ipv6:2001:db8::1]").is_ok());
+    }
+
+    #[test]
+    fn test_validate_international_email() {
+        let email = validate_international_email("josé@Bücher.de", EmailLevel::Project).unwrap();
+        assert_eq!((email.prefix(), email.unicode_domain(), email.ascii_domain()), ("josé", "bücher.de", "xn--bcher-kva.de"));
+        assert_eq!(email.to_unicode(), "josé@bücher.de");
+        assert_eq!(email.to_ascii(), "josé@xn--bcher-kva.de");
+        assert!(email.requires_smtputf8());
+
+        let email = validate_international_email("jo@xn--bcher-kva.de", EmailLevel::Html5).unwrap();
+        assert_eq!(email.to_unicode(), "jo@bücher.de");
+        assert!(!email.requires_smtputf8());
+        assert!(validate_international_email("用户@例子.广告", EmailLevel::Rfc5322).is_ok());
+        assert!(validate_international_email("\"josé doe\"@bücher.de", EmailLevel::Rfc5322).is_ok());
+        assert!(validate_email("josé@bücher.de").is_err());
+    }
+
+    #[test]
+    fn test_validate_international_email_reports_errors() {
+        let err = validate_international_email("jo@-bücher.de", EmailLevel::Project).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::InvalidInternationalDomain));
+        assert_eq!(err.span(), Span::new(3, 14));
+        let err = validate_international_email("josé..jo@bücher.de", EmailLevel::Project).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::MisplacedDot));
+        assert_eq!(err.span(), Span::new(5, 6));
+        let err = validate_international_email(&format!("{}@bücher.de", "é".repeat(33)), EmailLevel::Project).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::PrefixTooLong(66)));
+    }
+
+    #[test]
+    fn test_email_validator_with_unicode() {
+        let validator = EmailValidator::default().with_unicode(true);
+        assert_eq!(validator.normalize("josé@XN--BCHER-KVA.de").unwrap(), "josé@bücher.de");
+        assert_eq!(validator.normalize("Jo@FBI.gov").unwrap(), "Jo@fbi.gov");
+        assert_eq!(validator.components("josé@bücher.de").unwrap()[2], ("ascii-domain", "xn--bcher-kva.de".to_string()));
+        assert!(EmailValidator::default().validate("josé@bücher.de").is_err());
+    }
+
+    #[test]
+    fn test_phone_validator_normalizes_to_national_format() {
+        assert_eq!(PhoneValidator.normalize("206-301-1473").unwrap(), "(206) 301-1473");
+    }
+
+    #[test]
+    fn test_email_validator_lowercases_domain() {
+        assert_eq!(EmailValidator::default().normalize("NotAFed@FBI.Gov").unwrap(), "NotAFed@fbi.gov");
+    }
+
+    #[test]
+    fn test_phone_validator_components() {
+        let components = PhoneValidator.components("206-301-1473").unwrap();
+        assert_eq!(
+            components,
+            vec![
+                ("national", "(206) 301-1473".to_string()),
+                ("e164", "+12063011473".to_string()),
+                ("international", "+1 206-301-1473".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_email_validator_components() {
+        let components = EmailValidator::default().components("not_a_fed@fbi.gov").unwrap();
+        assert_eq!(components, vec![("prefix", "not_a_fed".to_string()), ("domain", "fbi.gov".to_string())]);
+    }
+
+    #[test]
+    fn test_validate_international_phone_number_e164() {
+        let phone = validate_international_phone_number("+442079460018", country::US).expect("number should be valid");
+        assert_eq!(phone.country(), Some(country::GB));
+        assert_eq!(phone.number_type(), Type::FixedLine);
+        assert_eq!(phone.number().format().mode(Mode::National).to_string(), "020 7946 0018");
+    }
+
+    #[test]
+    fn test_validate_international_phone_number_uses_default_region() {
+        let phone = validate_international_phone_number("07400 123456", country::GB).expect("number should be valid");
+        assert_eq!(phone.country(), Some(country::GB));
+        assert_eq!(phone.number_type(), Type::Mobile);
+        assert_eq!(phone.number().format().mode(Mode::E164).to_string(), "+447400123456");
+    }
+
+    #[test]
+    fn test_validate_international_phone_number_toll_free() {
+        let phone = validate_international_phone_number("1-800-555-0199", country::US).expect("number should be valid");
+        assert_eq!(phone.country(), Some(country::US));
+        assert_eq!(phone.number_type(), Type::TollFree);
+    }
+
+    #[test]
+    fn test_validate_international_phone_number_us_default() {
+        let phone = validate_international_phone_number("(206) 301-1473", country::US).expect("number should be valid");
+        assert_eq!(phone.number_type(), Type::FixedLineOrMobile);
+    }
+
+    #[test]
+    fn test_invalid_international_phone_number() {
+        let err = validate_international_phone_number("+44 20 7946", country::US).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::InvalidInternationalNumber));
+        let err = validate_international_phone_number("not a number", country::US).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::Unparseable));
+    }
+
+    #[test]
+    fn test_international_phone_validator_components() {
+        let components = InternationalPhoneValidator::new(country::DE).components("030 123456").unwrap();
+        assert_eq!(components[0], ("country", "DE".to_string()));
+        assert_eq!(components[1], ("country-code", "49".to_string()));
+        assert_eq!(components[4], ("e164", "+4930123456".to_string()));
+    }
+
+    #[test]
+    fn test_validate_phone_number_preserves_leading_zeros() {
+        let phone_number = validate_phone_number("206-301-0123").expect("number should be valid");
+        assert_eq!(format_phone_number(&phone_number, PhoneFormat::E164), "+12063010123");
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_country_code() {
+        let phone_number = validate_phone_number("+1 206 301 1473").expect("number should be valid");
+        assert_eq!(format_phone_number(&phone_number, PhoneFormat::E164), "+12063011473");
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_extension() {
+        for input in ["206-301-1473 x123", "(206) 301-1473 ext. 123", "2063011473, Ext 123", "206 301 1473 #123"] {
+            let phone_number = validate_phone_number(input).expect("number should be valid");
+            assert_eq!(phone_number.extension().map(|extension| extension.to_string()), Some("123".to_string()), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_phone_number_rejects_non_ascii_digits() {
+        for input in ["206-301-1473 x١٢", "206-301-1473 ext. １２", "٢٠٦-٣٠١-١٤٧٣", "(２０６) ３０１-１４７３"] {
+            assert!(validate_phone_number(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_vanity_letters() {
+        let phone_number = validate_phone_number("1-800-FLOWERS").expect("number should be valid");
+        assert_eq!(format_phone_number(&phone_number, PhoneFormat::E164), "+18003569377");
+    }
+
+    #[test]
+    fn test_validate_phone_number_vanity_ending_in_x() {
+        let phone_number = validate_phone_number("1-800-555-SAX9").expect("number should be valid");
+        assert_eq!(format_phone_number(&phone_number, PhoneFormat::E164), "+18005557299");
+        assert!(phone_number.extension().is_none());
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_strict_separators() {
+        for phone in ["(206) 301-1473", "(206)301-1473", "206-301-1473", "206 301 1473", "2063011473", "+1 206-301-1473", "1-800-FLOWERS"] {
+            assert!(validate_phone_number_with(phone, SeparatorMode::Strict).is_ok(), "{}", phone);
+        }
+        for (phone, span) in [("206-301 1473", Span::new(3, 8)), ("206301-1473", Span::new(3, 7)), ("(206)-301-1473", Span::new(5, 10))] {
+            assert!(validate_phone_number(phone).is_ok(), "{}", phone);
+            let err = validate_phone_number_with(phone, SeparatorMode::Strict).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::InconsistentSeparators), "{}", phone);
+            assert_eq!(err.span(), span, "{}", phone);
+        }
+    }
+
+    #[test]
+    fn test_validate_phone_number_with_unbalanced_parentheses() {
+        for (phone, span) in [("(206 301-1473", Span::new(0, 1)), ("206) 301-1473 x12", Span::new(3, 4))] {
+            assert!(validate_phone_number(phone).is_ok(), "{}", phone);
+            let err = validate_phone_number_with(phone, SeparatorMode::Strict).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Phone(PhoneError::UnbalancedParentheses), "{}", phone);
+            assert_eq!(err.span(), span, "{}", phone);
+        }
+    }
+
+    #[test]
+    fn test_format_phone_number() {
+        let phone_number = validate_phone_number("(206) 301-1473 x12").unwrap();
+        assert_eq!(format_phone_number(&phone_number, PhoneFormat::E164), "+12063011473");
+        assert_eq!(format_phone_number(&phone_number, PhoneFormat::National), "(206) 301-1473 ext. 12");
+        assert_eq!(format_phone_number(&phone_number, PhoneFormat::International), "+1 206-301-1473 ext. 12");
+        assert_eq!(format_phone_number(&phone_number, PhoneFormat::Rfc3966), "tel:+1-206-301-1473;ext=12");
+    }
+}
@@ -0,0 +1,163 @@
+//! Error types describing why a value failed validation.
+//!
+//! Every validator returns a [`ValidationError`] on failure. The error carries the specific
+//! rule that was broken as an [`ErrorKind`] (which wraps the per-field error enums such as
+//! [`SsnError`] or [`PasswordError`]) together with the [`Span`] of the input it refers to.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use crate::contact::{EmailError, PhoneError};
+use crate::finance::CurrencyError;
+use crate::identity::{NameError, SsnError};
+use crate::location::{AddressError, CityStateZipError};
+use crate::security::PasswordError;
+use crate::taxpayer::TaxpayerIdError;
+use crate::temporal::{DateError, MilitaryTimeError};
+use crate::web::UrlError;
+
+/// A byte range into the validated input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Span {
+    /// Byte offset of the first byte covered by the span.
+    pub start: usize,
+    /// Byte offset one past the last byte covered by the span.
+    pub end: usize,
+}
+
+impl Span {
+    /// Creates a span covering `start..end`.
+    pub fn new(start: usize, end: usize) -> Span {
+        Span { start, end }
+    }
+
+    /// Creates a span covering the whole of `input`.
+    pub fn whole(input: &str) -> Span {
+        Span::new(0, input.len())
+    }
+
+    /// Returns the span as a `Range<usize>`.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Returns the part of `input` covered by the span.
+    pub fn slice<'a>(&self, input: &'a str) -> &'a str {
+        &input[self.range()]
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(range: Range<usize>) -> Span {
+        Span::new(range.start, range.end)
+    }
+}
+
+impl<'t> From<regex::Match<'t>> for Span {
+    fn from(mat: regex::Match<'t>) -> Span {
+        Span::new(mat.start(), mat.end())
+    }
+}
+
+/// The specific validation rule that a value broke, grouped by field kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    Ssn(SsnError),
+    Name(NameError),
+    Phone(PhoneError),
+    Email(EmailError),
+    Date(DateError),
+    MilitaryTime(MilitaryTimeError),
+    Address(AddressError),
+    CityStateZip(CityStateZipError),
+    Currency(CurrencyError),
+    Url(UrlError),
+    Password(PasswordError),
+    TaxpayerId(TaxpayerIdError),
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Ssn(err) => err.fmt(f),
+            ErrorKind::Name(err) => err.fmt(f),
+            ErrorKind::Phone(err) => err.fmt(f),
+            ErrorKind::Email(err) => err.fmt(f),
+            ErrorKind::Date(err) => err.fmt(f),
+            ErrorKind::MilitaryTime(err) => err.fmt(f),
+            ErrorKind::Address(err) => err.fmt(f),
+            ErrorKind::CityStateZip(err) => err.fmt(f),
+            ErrorKind::Currency(err) => err.fmt(f),
+            ErrorKind::Url(err) => err.fmt(f),
+            ErrorKind::Password(err) => err.fmt(f),
+            ErrorKind::TaxpayerId(err) => err.fmt(f),
+        }
+    }
+}
+
+macro_rules! impl_from_field_error {
+    ($($variant:ident($error:ty)),* $(,)?) => {
+        $(
+            impl From<$error> for ErrorKind {
+                fn from(err: $error) -> ErrorKind {
+                    ErrorKind::$variant(err)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_field_error!(
+    Ssn(SsnError),
+    Name(NameError),
+    Phone(PhoneError),
+    Email(EmailError),
+    Date(DateError),
+    MilitaryTime(MilitaryTimeError),
+    Address(AddressError),
+    CityStateZip(CityStateZipError),
+    Currency(CurrencyError),
+    Url(UrlError),
+    Password(PasswordError),
+    TaxpayerId(TaxpayerIdError),
+);
+
+/// Explains why a value failed validation.
+///
+/// # Description
+///
+/// * `kind` names the rule that was broken, e.g. `SsnError::ForbiddenArea(666)`.
+/// * `span` is the byte range of the input that broke the rule. Rules that apply to the value
+///   as a whole (such as a minimum length) use a span covering the entire input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    kind: ErrorKind,
+    span: Span,
+}
+
+impl ValidationError {
+    /// Creates an error for the rule `kind`, broken by the part of the input covered by `span`.
+    pub fn new(kind: impl Into<ErrorKind>, span: impl Into<Span>) -> ValidationError {
+        ValidationError { kind: kind.into(), span: span.into() }
+    }
+
+    /// Returns the rule that was broken.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte range of the input that broke the rule.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (at bytes {}..{})", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl Error for ValidationError {}
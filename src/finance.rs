@@ -0,0 +1,318 @@
+//! Monetary amounts in US currency.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::{Span, ValidationError};
+use crate::validator::Validator;
+
+/// Matches a dollar amount with optional comma grouping and two digits of cents.
+static CURRENCY_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^\$(([0-9]{1,3}(,[0-9]{3})*(\.[0-9]{2})?)|([0-9]+(\.[0-9]{2})?))$").unwrap());
+
+/// The rule a currency amount broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CurrencyError {
+    /// The amount does not start with a dollar sign.
+    MissingDollarSign,
+    /// The amount contains a character other than digits, commas and a decimal point.
+    InvalidCharacter(char),
+    /// Commas do not separate the dollars into groups of three digits.
+    MisplacedComma,
+    /// The decimal point is not followed by exactly two digits of cents.
+    InvalidCents,
+    /// The amount is too large to represent in cents.
+    TooLarge,
+    /// The amount does not match the currency format for another reason.
+    Malformed,
+}
+
+impl fmt::Display for CurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CurrencyError::MissingDollarSign => write!(f, "amount must start with a dollar sign"),
+            CurrencyError::InvalidCharacter(ch) => write!(f, "amount may not contain {:?}", ch),
+            CurrencyError::MisplacedComma => write!(f, "commas must separate dollars into groups of three digits"),
+            CurrencyError::InvalidCents => write!(f, "cents must be exactly two digits"),
+            CurrencyError::TooLarge => write!(f, "amount is too large"),
+            CurrencyError::Malformed => write!(f, "amount must be in the form $1,234.56"),
+        }
+    }
+}
+
+/// A valid US dollar amount, stored as a whole number of cents.
+///
+/// # Description
+///
+/// * Produced by [`validate_currency`] or by parsing a string with [`str::parse`].
+/// * Displays with comma grouped dollars and two digits of cents, e.g. `$1,234.50`.
+/// * Orders by value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct UsdAmount {
+    cents: u64,
+}
+
+impl UsdAmount {
+    /// Creates an amount of `cents` cents.
+    pub fn from_cents(cents: u64) -> UsdAmount {
+        UsdAmount { cents }
+    }
+
+    /// Returns the amount in cents.
+    pub fn cents(&self) -> u64 {
+        self.cents
+    }
+
+    /// Returns the whole dollars of the amount, dropping the cents.
+    pub fn dollars(&self) -> u64 {
+        self.cents / 100
+    }
+}
+
+impl fmt::Display for UsdAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.dollars().to_string();
+        let mut grouped = String::new();
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        write!(f, "${}.{:02}", grouped, self.cents % 100)
+    }
+}
+
+impl FromStr for UsdAmount {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<UsdAmount, ValidationError> {
+        validate_currency(s)
+    }
+}
+
+/// Validates a currency amount string by checking if it matches the expected format.
+///
+/// # Description
+///
+/// * This function checks if the input string matches the currency amount format, which consists of:
+///   - A dollar sign ($)
+///   - An optional group of 1 to 3 digits followed by optional groups of 3 digits separated by commas
+///   - An optional decimal point followed by exactly 2 digits
+/// * The input string must start with a dollar sign ($).
+///
+/// # Arguments
+///
+/// * `amount: &str` - The input string containing the currency amount to validate.
+///
+/// # Returns
+///
+/// * `Result<UsdAmount, ValidationError>` - Returns the parsed [`UsdAmount`] if the input matches the expected
+///   currency amount format, or the broken rule (a [`CurrencyError`]) otherwise.
+pub fn validate_currency(amount: &str) -> Result<UsdAmount, ValidationError> {
+    if !CURRENCY_REGEX.is_match(amount) {
+        return Err(diagnose_currency(amount));
+    }
+
+    // Sum the dollars and cents digits into a whole number of cents, ignoring the grouping commas.
+    let (dollars, cents) = amount[1..].split_once('.').unwrap_or((&amount[1..], "00"));
+    let cents = dollars
+        .bytes()
+        .chain(cents.bytes())
+        .filter(u8::is_ascii_digit)
+        .try_fold(0u64, |total, digit| total.checked_mul(10)?.checked_add(u64::from(digit - b'0')))
+        .ok_or_else(|| ValidationError::new(CurrencyError::TooLarge, Span::whole(amount)))?;
+    Ok(UsdAmount { cents })
+}
+
+/// Explains why `amount` does not match the currency format.
+fn diagnose_currency(amount: &str) -> ValidationError {
+    let Some(number) = amount.strip_prefix('$') else {
+        return ValidationError::new(CurrencyError::MissingDollarSign, Span::new(0, 0));
+    };
+    if let Some((i, ch)) = number.char_indices().find(|(_, ch)| !(ch.is_ascii_digit() || *ch == ',' || *ch == '.')) {
+        return ValidationError::new(CurrencyError::InvalidCharacter(ch), Span::new(1 + i, 1 + i + ch.len_utf8()));
+    }
+
+    let (dollars, cents) = match number.split_once('.') {
+        Some((dollars, cents)) => (dollars, Some(cents)),
+        None => (number, None),
+    };
+    if let Some(cents) = cents {
+        if cents.len() != 2 || !cents.bytes().all(|b| b.is_ascii_digit()) {
+            let start = 1 + dollars.len() + 1;
+            return ValidationError::new(CurrencyError::InvalidCents, Span::new(start, start + cents.len()));
+        }
+    }
+
+    let groups: Vec<&str> = dollars.split(',').collect();
+    if groups.len() > 1 {
+        let first_ok = (1..=3).contains(&groups[0].len());
+        if !first_ok || groups[1..].iter().any(|group| group.len() != 3) {
+            return ValidationError::new(CurrencyError::MisplacedComma, Span::new(1, 1 + dollars.len()));
+        }
+    }
+
+    ValidationError::new(CurrencyError::Malformed, Span::whole(amount))
+}
+
+/// The [`Validator`] for US currency amounts; see [`validate_currency`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CurrencyValidator;
+
+impl Validator for CurrencyValidator {
+    fn name(&self) -> &'static str {
+        "currency"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "Must start with a dollar sign ($).",
+            "Dollars may be grouped in threes by commas.",
+            "An optional decimal point followed by exactly 2 digits of cents.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_currency(input).map(|_| ())
+    }
+
+    /// Normalizes the amount to comma grouped dollars with cents, e.g. `$1234` becomes `$1,234.00`.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        validate_currency(input).map(|amount| amount.to_string())
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        let amount = validate_currency(input)?;
+        Ok(vec![("dollars", amount.dollars().to_string()), ("cents", (amount.cents() % 100).to_string())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_validate_currency_valid_no_cents() {
+        assert!(validate_currency("$1000").is_ok());
+    }
+
+    #[test]
+    fn test_validate_currency_valid_with_cents() {
+        assert!(validate_currency("$1234.56").is_ok());
+    }
+
+    #[test]
+    fn test_validate_currency_valid_with_commas() {
+        assert!(validate_currency("$1,234,567.89").is_ok());
+    }
+
+    #[test]
+    fn test_validate_currency_valid_one_cent() {
+        assert!(validate_currency("$0.01").is_ok());
+    }
+
+    #[test]
+    fn test_validate_currency_valid_no_decimal_cents() {
+        assert!(validate_currency("$1000.00").is_ok());
+    }
+
+    #[test]
+    fn test_validate_currency_invalid_missing_dollar_sign() {
+        assert!(validate_currency("1234.56").is_err());
+    }
+
+    #[test]
+    fn test_validate_currency_invalid_wrong_decimal_places() {
+        assert!(validate_currency("$1234.567").is_err());
+    }
+
+    #[test]
+    fn test_validate_currency_invalid_non_numeric() {
+        assert!(validate_currency("$1,234.5a").is_err());
+    }
+
+    #[test]
+    fn test_validate_currency_invalid_comma_position() {
+        assert!(validate_currency("$12,34.56").is_err());
+    }
+
+    #[test]
+    fn test_validate_currency_invalid_extra_dollar_sign() {
+        assert!(validate_currency("$1$234.56").is_err());
+    }
+
+    #[test]
+    fn test_validate_currency_reports_missing_dollar_sign() {
+        let err = validate_currency("1234.56").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Currency(CurrencyError::MissingDollarSign));
+    }
+
+    #[test]
+    fn test_validate_currency_reports_invalid_cents() {
+        let err = validate_currency("$1234.567").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Currency(CurrencyError::InvalidCents));
+        assert_eq!(err.span(), Span::new(6, 9));
+    }
+
+    #[test]
+    fn test_validate_currency_reports_misplaced_comma() {
+        let err = validate_currency("$12,34.56").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Currency(CurrencyError::MisplacedComma));
+    }
+
+    #[test]
+    fn test_validate_currency_reports_invalid_character() {
+        let err = validate_currency("$1$234.56").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Currency(CurrencyError::InvalidCharacter('$')));
+        assert_eq!(err.span(), Span::new(2, 3));
+    }
+
+    #[test]
+    fn test_validate_currency_rejects_non_ascii_digits() {
+        let err = validate_currency("$١٢٣").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Currency(CurrencyError::InvalidCharacter('١')));
+        assert_eq!(err.span(), Span::new(1, 3));
+        let err = validate_currency("$1٢3.4٥").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Currency(CurrencyError::InvalidCharacter('٢')));
+        assert_eq!(err.span(), Span::new(2, 4));
+    }
+
+    #[test]
+    fn test_currency_validator_normalizes_grouping_and_cents() {
+        assert_eq!(CurrencyValidator.normalize("$1234567").unwrap(), "$1,234,567.00");
+        assert_eq!(CurrencyValidator.normalize("$1,234.56").unwrap(), "$1,234.56");
+        assert_eq!(CurrencyValidator.normalize("$000.01").unwrap(), "$0.01");
+    }
+
+    #[test]
+    fn test_validate_currency_returns_cents() {
+        assert_eq!(validate_currency("$1,234,567.89").unwrap().cents(), 123_456_789);
+        assert_eq!(validate_currency("$1000").unwrap().cents(), 100_000);
+        assert_eq!(validate_currency("$0.01").unwrap().cents(), 1);
+    }
+
+    #[test]
+    fn test_validate_currency_reports_too_large() {
+        let err = validate_currency("$999999999999999999999").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Currency(CurrencyError::TooLarge));
+    }
+
+    #[test]
+    fn test_usd_amount_display_round_trips() {
+        let amount = UsdAmount::from_cents(123_456_780);
+        assert_eq!(amount.to_string(), "$1,234,567.80");
+        assert_eq!(amount.to_string().parse::<UsdAmount>().unwrap(), amount);
+    }
+
+    #[test]
+    fn test_usd_amount_orders_by_value() {
+        let small: UsdAmount = "$999.99".parse().unwrap();
+        let large: UsdAmount = "$1,000".parse().unwrap();
+        assert!(small < large);
+        assert_eq!(large.dollars(), 1000);
+    }
+}
@@ -0,0 +1,576 @@
+//! Synthetic test data: random valid and deliberately invalid samples of every field kind.
+//!
+//! A [`Generator`] is seeded, so the same seed always produces the same samples, on every
+//! platform and across releases of this crate's dependencies (the generator has its own
+//! SplitMix64 random number generator rather than relying on an external crate's stream).
+//!
+//! ```
+//! use regular_expressions::generate::{Field, Generator};
+//! use regular_expressions::validate_ssn;
+//!
+//! let mut generator = Generator::new(42);
+//! assert!(validate_ssn(&generator.valid(Field::Ssn)).is_ok());
+//! assert!(validate_ssn(&generator.invalid(Field::Ssn)).is_err());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::finance::UsdAmount;
+use crate::identity::Ssn;
+use crate::location::STREET_TYPES;
+use crate::temporal::days_in_month;
+use crate::validate_phone_number;
+
+/// Area codes used for generated phone numbers; each is assigned in the phone number metadata.
+const AREA_CODES: [u16; 12] = [206, 212, 303, 312, 415, 509, 617, 702, 713, 808, 919, 971];
+
+/// Street names used for generated addresses.
+const STREET_NAMES: [&str; 10] =
+    ["Elm", "Oak", "Maple", "Cherry Tree", "Pine", "Cedar", "Main", "Washington", "Lake View", "Hill"];
+
+/// City and state pairs used for generated city, state and ZIP code lines.
+const CITIES: [(&str, &str); 8] = [
+    ("Seattle", "WA"),
+    ("New York", "NY"),
+    ("Miami", "FL"),
+    ("Boston", "MA"),
+    ("Austin", "TX"),
+    ("Salt Lake City", "UT"),
+    ("Denver", "CO"),
+    ("Portland", "OR"),
+];
+
+/// Surnames and given names used for generated roster names.
+const NAMES: [&str; 10] = ["Doe", "Roe", "Smith", "Garcia", "Nguyen", "Okafor", "Kowalski", "Lee", "John", "Maria"];
+
+/// Top-level domains used for generated emails and URLs.
+const TOP_LEVEL_DOMAINS: [&str; 6] = ["com", "org", "gov", "io", "co", "museum"];
+
+/// Special characters allowed in an email prefix.
+const EMAIL_SPECIALS: &str = "!#$%&'*+/=?^_`{|}~-";
+
+/// Punctuation characters counted by the password rules.
+const PUNCTUATION: &str = "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~";
+
+/// The field kinds a [`Generator`] produces samples of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Field {
+    Ssn,
+    Phone,
+    Email,
+    Name,
+    Date,
+    MilitaryTime,
+    Address,
+    CityStateZip,
+    Currency,
+    Url,
+    Password,
+    OddIonWord,
+}
+
+impl Field {
+    /// Every field kind.
+    pub const ALL: [Field; 12] = [
+        Field::Ssn,
+        Field::Phone,
+        Field::Email,
+        Field::Name,
+        Field::Date,
+        Field::MilitaryTime,
+        Field::Address,
+        Field::CityStateZip,
+        Field::Currency,
+        Field::Url,
+        Field::Password,
+        Field::OddIonWord,
+    ];
+
+    /// Returns the name of the field kind, matching the [`Validator::name`](crate::validator::Validator::name)
+    /// of its validator, or `"odd-ion-words"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Field::Ssn => "ssn",
+            Field::Phone => "phone",
+            Field::Email => "email",
+            Field::Name => "name",
+            Field::Date => "date",
+            Field::MilitaryTime => "military-time",
+            Field::Address => "address",
+            Field::CityStateZip => "city-state-zip",
+            Field::Currency => "currency",
+            Field::Url => "url",
+            Field::Password => "password",
+            Field::OddIonWord => "odd-ion-words",
+        }
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Field {
+    type Err = String;
+
+    /// Parses a field kind from its [`Field::name`].
+    fn from_str(name: &str) -> Result<Field, String> {
+        Field::ALL.into_iter().find(|field| field.name() == name).ok_or_else(|| {
+            let names: Vec<&str> = Field::ALL.iter().map(Field::name).collect();
+            format!("unknown field {:?}, expected one of: {}", name, names.join(", "))
+        })
+    }
+}
+
+/// Produces reproducible random samples of each field kind.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    /// Creates a generator; the same `seed` always produces the same sequence of samples.
+    pub fn new(seed: u64) -> Generator {
+        Generator { state: seed }
+    }
+
+    /// Returns a random value of `field` that its validator accepts.
+    pub fn valid(&mut self, field: Field) -> String {
+        match field {
+            Field::Ssn => self.valid_ssn(),
+            Field::Phone => self.valid_phone(),
+            Field::Email => self.valid_email(),
+            Field::Name => self.valid_name(),
+            Field::Date => self.valid_date(),
+            Field::MilitaryTime => format!("{:02}{:02}", self.below(24), self.below(60)),
+            Field::Address => self.valid_address(),
+            Field::CityStateZip => self.valid_city_state_zip(),
+            Field::Currency => self.valid_currency(),
+            Field::Url => self.valid_url(),
+            Field::Password => self.valid_password(),
+            Field::OddIonWord => self.odd_ion_word(true),
+        }
+    }
+
+    /// Returns a random value of `field` that breaks one of its validator's rules, chosen at random.
+    pub fn invalid(&mut self, field: Field) -> String {
+        match field {
+            Field::Ssn => self.invalid_ssn(),
+            Field::Phone => self.invalid_phone(),
+            Field::Email => self.invalid_email(),
+            Field::Name => self.invalid_name(),
+            Field::Date => self.invalid_date(),
+            Field::MilitaryTime => self.invalid_military_time(),
+            Field::Address => self.invalid_address(),
+            Field::CityStateZip => self.invalid_city_state_zip(),
+            Field::Currency => self.invalid_currency(),
+            Field::Url => self.invalid_url(),
+            Field::Password => self.invalid_password(),
+            Field::OddIonWord => self.odd_ion_word(false),
+        }
+    }
+
+    /// Returns the next 64 random bits (SplitMix64).
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number in `0..n`.
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Returns a random number in `low..=high`.
+    fn between(&mut self, low: u64, high: u64) -> u64 {
+        low + self.below(high - low + 1)
+    }
+
+    /// Returns a random element of `items`.
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Returns a random character of `chars`.
+    fn pick_char(&mut self, chars: &str) -> char {
+        let chars: Vec<char> = chars.chars().collect();
+        self.pick(&chars)
+    }
+
+    /// Returns a random ASCII lowercase letter.
+    fn lowercase(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+
+    /// Returns between `min` and `max` random lowercase letters.
+    fn letters(&mut self, min: u64, max: u64) -> String {
+        let len = self.between(min, max);
+        (0..len).map(|_| self.lowercase()).collect()
+    }
+
+    /// Returns one of the separators the SSN and phone validators accept between digit groups.
+    fn digit_separator(&mut self) -> &'static str {
+        self.pick(&["-", " ", ""])
+    }
+
+    fn valid_ssn(&mut self) -> String {
+        let area = loop {
+            let area = self.between(1, 899);
+            if area != 666 {
+                break area;
+            }
+        };
+        let separator = self.digit_separator();
+        let (group, serial) = (self.between(1, 99), self.between(1, 9999));
+        // Sample numbers such as 123-45-6789 are invalid, so replace them with a neighbor.
+        let serial = if Ssn::new(area as u16, group as u8, serial as u16).is_some() { serial } else { serial % 9999 + 1 };
+        format!("{:03}{sep}{:02}{sep}{:04}", area, group, serial, sep = separator)
+    }
+
+    fn invalid_ssn(&mut self) -> String {
+        let (area, group, serial) = (self.between(1, 665), self.between(1, 99), self.between(1, 9999));
+        match self.below(7) {
+            0 => format!("000-{:02}-{:04}", group, serial),
+            1 => format!("666-{:02}-{:04}", group, serial),
+            2 => format!("{}-{:02}-{:04}", self.between(900, 999), group, serial),
+            3 => format!("{:03}-00-{:04}", area, serial),
+            4 => format!("{:03}-{:02}-0000", area, group),
+            5 => self.pick(&["078-05-1120", "219-09-9999", "123-45-6789"]).to_string(),
+            _ => format!("{:02}-{:03}-{:04}", area % 100, group, serial),
+        }
+    }
+
+    fn valid_phone(&mut self) -> String {
+        loop {
+            let (area, exchange, line) = (self.pick(&AREA_CODES), self.between(200, 999), self.below(10_000));
+            let phone = match self.below(5) {
+                0 => format!("({}) {}-{:04}", area, exchange, line),
+                1 => format!("{}-{}-{:04}", area, exchange, line),
+                2 => format!("{} {} {:04}", area, exchange, line),
+                3 => format!("{}{}{:04}", area, exchange, line),
+                _ => format!("+1 {} {} {:04}", area, exchange, line),
+            };
+            // Some exchanges (such as N11 service codes) are never assigned, so draw again.
+            if validate_phone_number(&phone).is_ok() {
+                return phone;
+            }
+        }
+    }
+
+    fn invalid_phone(&mut self) -> String {
+        let (area, exchange, line) = (self.pick(&AREA_CODES), self.between(200, 999), self.below(10_000));
+        match self.below(4) {
+            0 => format!("{}-{}-{:03}", area, exchange, line % 1000),
+            1 => format!("{}{:02}-{}-{:04}", self.below(2), self.below(100), exchange, line),
+            2 => format!("{}-{}-{:04}-{}", area, exchange, line, self.below(10)),
+            _ => format!("{}-{}-{:04}!", area, exchange, line),
+        }
+    }
+
+    /// Returns a prefix of dot separated atoms of letters, digits and special characters.
+    fn email_prefix(&mut self) -> String {
+        let atoms = self.between(1, 3);
+        let atoms: Vec<String> = (0..atoms)
+            .map(|_| {
+                let mut atom = self.letters(1, 6);
+                if self.below(4) == 0 {
+                    atom.push(self.pick_char(EMAIL_SPECIALS));
+                    atom.push(self.lowercase());
+                }
+                if self.below(3) == 0 {
+                    atom.push_str(&self.below(100).to_string());
+                }
+                atom
+            })
+            .collect();
+        atoms.join(".")
+    }
+
+    /// Returns a domain of one or two labels and a top-level domain.
+    fn domain(&mut self) -> String {
+        let mut labels: Vec<String> = (0..self.between(1, 2)).map(|_| self.letters(2, 8)).collect();
+        if self.below(4) == 0 {
+            labels[0] = format!("{}-{}", labels[0], self.letters(3, 3));
+        }
+        labels.push(self.pick(&TOP_LEVEL_DOMAINS).to_string());
+        labels.join(".")
+    }
+
+    fn valid_email(&mut self) -> String {
+        format!("{}@{}", self.email_prefix(), self.domain())
+    }
+
+    fn invalid_email(&mut self) -> String {
+        let (prefix, domain) = (self.email_prefix(), self.domain());
+        match self.below(7) {
+            0 => format!("{}{}", prefix, domain),
+            1 => format!("{}@{}@{}", prefix, self.letters(4, 4), domain),
+            2 => format!(".{}@{}", prefix, domain),
+            3 => format!("{}..{}@{}", prefix, self.letters(3, 3), domain),
+            4 => format!("{}@{}", prefix, self.letters(6, 6)),
+            5 => format!("{}@-{}", prefix, domain),
+            _ => format!("{} {}@{}", prefix, self.letters(3, 3), domain),
+        }
+    }
+
+    fn valid_name(&mut self) -> String {
+        let mut name = format!("{}, {}", self.pick(&NAMES), self.pick(&NAMES));
+        for _ in 0..self.below(4) {
+            name.push_str(", ");
+            name.push(self.lowercase().to_ascii_uppercase());
+        }
+        name
+    }
+
+    fn invalid_name(&mut self) -> String {
+        let (last, first) = (self.pick(&NAMES), self.pick(&NAMES));
+        match self.below(4) {
+            0 => format!("{} {}", last, first),
+            1 => format!("{}{}, {}", last, self.below(10), first),
+            2 => format!("{}, {}, {}", last, first, self.letters(2, 2).to_ascii_uppercase()),
+            _ => format!("{}, , {}", last, self.lowercase().to_ascii_uppercase()),
+        }
+    }
+
+    fn valid_date(&mut self) -> String {
+        let (year, month) = (self.between(1900, 2099) as u16, self.between(1, 12) as u8);
+        let day = self.between(1, u64::from(days_in_month(year, month).unwrap()));
+        let separator = self.pick(&["/", "-"]);
+        format!("{:02}{sep}{:02}{sep}{}", month, day, year, sep = separator)
+    }
+
+    fn invalid_date(&mut self) -> String {
+        let (year, month) = (self.between(1900, 2099) as u16, self.between(1, 12) as u8);
+        let max = days_in_month(year, month).unwrap();
+        match self.below(5) {
+            0 => format!("{:02}/01/{}", self.pick(&[0, 13, 20, 99]), year),
+            1 => format!("{:02}/{:02}/{}", month, self.between(u64::from(max) + 1, 39), year),
+            2 => format!("{:02}/00/{}", month, year),
+            3 => format!("{}-{:02}-{:02}", year, month, self.between(1, 28)),
+            _ => format!("{:02}/{:02}/{:02}", month, self.between(1, 28), year % 100),
+        }
+    }
+
+    fn invalid_military_time(&mut self) -> String {
+        match self.below(4) {
+            0 => format!("{}{:02}", self.between(24, 99), self.below(60)),
+            1 => format!("{:02}{}", self.below(24), self.between(60, 99)),
+            2 => format!("{:02}:{:02}", self.below(24), self.below(60)),
+            _ => format!("{}{:02}", self.below(10), self.below(60)),
+        }
+    }
+
+    /// Returns a street type in its full or abbreviated form, capitalized.
+    fn street_type(&mut self) -> String {
+        let street_type = self.pick(&STREET_TYPES);
+        street_type[..1].to_ascii_uppercase() + &street_type[1..]
+    }
+
+    fn valid_address(&mut self) -> String {
+        format!("{} {} {}", self.between(1, 99_999), self.pick(&STREET_NAMES), self.street_type())
+    }
+
+    fn invalid_address(&mut self) -> String {
+        let (number, street) = (self.between(1, 99_999), self.pick(&STREET_NAMES));
+        match self.below(3) {
+            0 => format!("{} {}", street, self.street_type()),
+            1 => format!("{} {}", number, self.street_type()),
+            _ => format!("{} {} {}", number, street, self.pick(&["Lane", "Way", "Court", "Ro"])),
+        }
+    }
+
+    /// Returns a ZIP code with an optional four digit extension.
+    fn zip(&mut self) -> String {
+        let zip = format!("{:05}", self.below(100_000));
+        if self.below(3) == 0 {
+            format!("{}-{:04}", zip, self.below(10_000))
+        } else {
+            zip
+        }
+    }
+
+    fn valid_city_state_zip(&mut self) -> String {
+        let (city, state) = self.pick(&CITIES);
+        format!("{}, {} {}", city, state, self.zip())
+    }
+
+    fn invalid_city_state_zip(&mut self) -> String {
+        let (city, state) = self.pick(&CITIES);
+        let zip = self.zip();
+        match self.below(4) {
+            0 => format!("{} {} {}", city, state, zip),
+            1 => format!("{}, {} {}", city, self.pick(&["ZZ", "FLA", "Wa", "XY"]), zip),
+            2 => format!("{}, {} {}", city, state, self.below(10_000)),
+            _ => format!("{}{}, {} {}", city, self.below(10), state, zip),
+        }
+    }
+
+    fn valid_currency(&mut self) -> String {
+        let digits = self.between(1, 9) as u32;
+        let dollars = self.below(10u64.pow(digits));
+        let dollars = if self.below(2) == 0 {
+            // Displayed amounts group the dollars with commas; keep them without the sign and cents.
+            let amount = UsdAmount::from_cents(dollars * 100).to_string();
+            amount[1..amount.len() - 3].to_string()
+        } else {
+            dollars.to_string()
+        };
+        match self.below(2) {
+            0 => format!("${}", dollars),
+            _ => format!("${}.{:02}", dollars, self.below(100)),
+        }
+    }
+
+    fn invalid_currency(&mut self) -> String {
+        let dollars = self.between(1000, 999_999);
+        match self.below(5) {
+            0 => format!("{}.{:02}", dollars, self.below(100)),
+            1 => format!("${},{}", dollars / 100, dollars % 100),
+            2 => format!("${}.{}", dollars, self.below(10)),
+            3 => format!("${}{}", dollars, self.lowercase()),
+            _ => format!("${}.{:03}", dollars, self.below(1000)),
+        }
+    }
+
+    fn valid_url(&mut self) -> String {
+        let scheme = self.pick(&["", "http://", "https://"]);
+        let www = self.pick(&["", "www."]);
+        let mut url = format!("{}{}{}", scheme, www, self.domain());
+        if self.below(2) == 0 {
+            url.push('/');
+            url.push_str(&self.letters(0, 7));
+            if self.below(2) == 0 {
+                url.push_str(&format!("?id={}", self.below(1000)));
+            }
+        }
+        url
+    }
+
+    fn invalid_url(&mut self) -> String {
+        let (label, path) = (self.letters(2, 8), self.letters(4, 4));
+        match self.below(4) {
+            0 => format!("{}://{}.com", self.pick(&["ftp", "file", "gopher"]), label),
+            1 => format!("https://{}.{}", label, self.lowercase()),
+            2 => format!("https://{}.com/{} {}", label, path, self.letters(3, 3)),
+            _ => format!("https://-{}.com", label),
+        }
+    }
+
+    fn valid_password(&mut self) -> String {
+        let len = self.between(10, 16) as usize;
+        let mut password: Vec<char> = (0..len)
+            .map(|_| match self.below(4) {
+                0 => self.lowercase().to_ascii_uppercase(),
+                1 => self.lowercase(),
+                2 => (b'0' + self.below(10) as u8) as char,
+                _ => self.pick_char(PUNCTUATION),
+            })
+            .collect();
+        // Make sure every required class appears at a distinct position.
+        let mut positions: Vec<usize> = (0..len).collect();
+        for (i, required) in [self.lowercase().to_ascii_uppercase(), self.lowercase(), '7', '#'].into_iter().enumerate() {
+            let at = i + self.below((len - i) as u64) as usize;
+            positions.swap(i, at);
+            password[positions[i]] = required;
+        }
+        // Break up runs of more than three lowercase letters.
+        let mut run = 0;
+        for (i, ch) in password.clone().into_iter().enumerate() {
+            run = if ch.is_ascii_lowercase() { run + 1 } else { 0 };
+            if run == 4 {
+                password[i] = (b'0' + self.below(10) as u8) as char;
+                run = 0;
+            }
+        }
+        password.into_iter().collect()
+    }
+
+    fn invalid_password(&mut self) -> String {
+        let password = self.valid_password();
+        match self.below(6) {
+            0 => password[..self.between(1, 9) as usize].to_string(),
+            1 => password.replace(|ch: char| ch.is_ascii_uppercase(), "1"),
+            2 => password.replace(|ch: char| ch.is_ascii_lowercase(), "X"),
+            3 => password.replace(|ch: char| ch.is_ascii_digit(), "Y"),
+            4 => password.replace(|ch: char| ch.is_ascii_punctuation(), "Z"),
+            _ => format!("{}{}", password, self.letters(4, 8)),
+        }
+    }
+
+    /// Returns a word ending in "ion" with an odd (or, if `odd` is false, even) number of letters
+    /// before the "ion".
+    fn odd_ion_word(&mut self, odd: bool) -> String {
+        let before = self.below(4) * 2 + u64::from(odd);
+        let before = if before == 0 { 2 } else { before };
+        let mut word = self.letters(before, before);
+        if !odd && self.below(2) == 0 {
+            // Or an odd number of letters not ending in "ion".
+            word.push_str(&self.letters(1, 1));
+            word.push_str("ions");
+        } else {
+            word.push_str("ion");
+        }
+        word
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator;
+    use crate::validate_odd_ion_words;
+
+    /// Returns whether `input` passes the validator of `field`.
+    fn is_valid(field: Field, input: &str) -> bool {
+        match field {
+            Field::OddIonWord => validate_odd_ion_words(input) == vec![input.to_string()],
+            _ => validator::by_name(field.name()).unwrap().validate(input).is_ok(),
+        }
+    }
+
+    #[test]
+    fn test_valid_samples_pass_their_validator() {
+        let mut generator = Generator::new(1);
+        for field in Field::ALL {
+            for _ in 0..500 {
+                let sample = generator.valid(field);
+                assert!(is_valid(field, &sample), "generated {} {:?} is not valid", field, sample);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_samples_fail_their_validator() {
+        let mut generator = Generator::new(2);
+        for field in Field::ALL {
+            for _ in 0..500 {
+                let sample = generator.invalid(field);
+                assert!(!is_valid(field, &sample), "generated {} {:?} is valid", field, sample);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generator_is_reproducible() {
+        let samples = |seed| {
+            let mut generator = Generator::new(seed);
+            Field::ALL.map(|field| generator.valid(field))
+        };
+        assert_eq!(samples(7), samples(7));
+        assert_ne!(samples(7), samples(8));
+    }
+
+    #[test]
+    fn test_field_names_round_trip() {
+        for field in Field::ALL {
+            assert_eq!(field.name().parse::<Field>(), Ok(field));
+        }
+    }
+}
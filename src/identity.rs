@@ -0,0 +1,818 @@
+//! Personal identity fields: Social Security Numbers and class roster names.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::{Span, ValidationError};
+use crate::validator::{SeparatorMode, Validator};
+
+/// Matches a nine-digit SSN, capturing the area, group and serial numbers.
+static SSN_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<area>[0-9]{3})(?P<first_separator>[-\s]?)(?P<group>[0-9]{2})(?P<second_separator>[-\s]?)(?P<serial>[0-9]{4})$").unwrap()
+});
+
+/// The bundled table of the states SSN area numbers were assigned to.
+const SSN_AREAS: &str = include_str!("../data/ssn_areas.txt");
+
+/// The ranges of area numbers in [`SSN_AREAS`], with the state each was assigned to.
+static SSN_AREA_STATES: LazyLock<Vec<(u16, u16, &'static str)>> = LazyLock::new(|| {
+    SSN_AREAS
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (areas, state) = line.split_once(' ').expect("every line of the area table names a state");
+            let (first, last) = areas.split_once('-').unwrap_or((areas, areas));
+            (first.parse().unwrap(), last.parse().unwrap(), state)
+        })
+        .collect()
+});
+
+/// SSNs that were published in advertising or as samples, and so are used by mistake or as fakes.
+///
+/// * 078-05-1120 was printed on a sample card sold with wallets by Woolworth in 1938.
+/// * 219-09-9999 was printed on a sample card in a 1940 Social Security Board pamphlet.
+/// * 123-45-6789 and the SSNs made of one repeated digit are common test numbers.
+const SAMPLE_SSNS: [(u16, u8, u16); 10] = [
+    (78, 5, 1120),
+    (219, 9, 9999),
+    (123, 45, 6789),
+    (111, 11, 1111),
+    (222, 22, 2222),
+    (333, 33, 3333),
+    (444, 44, 4444),
+    (555, 55, 5555),
+    (777, 77, 7777),
+    (888, 88, 8888),
+];
+
+/// Matches a "Last, First, MI" roster name.
+static NAME_ROSTER_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?P<last>[a-zA-Z]+),\s*(?P<first>[a-zA-Z]+)(,\s*(?P<middle>[a-zA-Z]))*$").unwrap());
+
+/// The rule a Social Security Number broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SsnError {
+    /// The input is not nine digits, optionally split 3-2-4 by dashes or spaces.
+    Malformed,
+    /// The area number (first three digits) is 000.
+    ZeroArea,
+    /// The group number (middle two digits) is 00.
+    ZeroGroup,
+    /// The serial number (last four digits) is 0000.
+    ZeroSerial,
+    /// The area number is 666 or in the range 900-999, which are never issued as SSNs.
+    ForbiddenArea(u16),
+    /// The SSN is a well-known advertising or sample number, such as 078-05-1120.
+    SampleNumber,
+    /// The area number had not been issued before SSNs were randomized in June 2011.
+    AreaNotIssued(u16),
+    /// The group number had not been issued in its area before SSNs were randomized in June 2011.
+    GroupNotIssued { area: u16, group: u8 },
+    /// The two separators differ, e.g. `123-45 6789`, which [`SeparatorMode::Strict`] rejects.
+    InconsistentSeparators,
+}
+
+impl fmt::Display for SsnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SsnError::Malformed => write!(f, "SSN must be nine digits in the form AAA-GG-SSSS"),
+            SsnError::ZeroArea => write!(f, "SSN area number must not be 000"),
+            SsnError::ZeroGroup => write!(f, "SSN group number must not be 00"),
+            SsnError::ZeroSerial => write!(f, "SSN serial number must not be 0000"),
+            SsnError::ForbiddenArea(area) => write!(f, "SSN area number {:03} is never issued", area),
+            SsnError::SampleNumber => write!(f, "SSN is a well-known advertising or sample number"),
+            SsnError::AreaNotIssued(area) => write!(f, "SSN area number {:03} was not issued before June 25, 2011", area),
+            SsnError::GroupNotIssued { area, group } => {
+                write!(f, "SSN group number {:02} was not issued in area {:03} before June 25, 2011", group, area)
+            }
+            SsnError::InconsistentSeparators => write!(f, "SSN separators must be both dashes, both spaces or both absent"),
+        }
+    }
+}
+
+/// The rule a class roster name broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameError {
+    /// The last name is not followed by a comma and the first name.
+    MissingComma,
+    /// One of the comma separated parts of the name is empty.
+    EmptyPart,
+    /// A name part contains a character other than a letter.
+    InvalidCharacter(char),
+    /// A middle initial is longer than one letter.
+    MiddleInitialTooLong,
+    /// The name does not match the roster format for another reason.
+    Malformed,
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameError::MissingComma => write!(f, "last name must be followed by a comma and the first name"),
+            NameError::EmptyPart => write!(f, "name parts must not be empty"),
+            NameError::InvalidCharacter(ch) => write!(f, "name parts may only contain letters, found {:?}", ch),
+            NameError::MiddleInitialTooLong => write!(f, "middle initials must be a single letter"),
+            NameError::Malformed => write!(f, "name must be in the form \"Last, First, MI\""),
+        }
+    }
+}
+
+/// A valid US Social Security Number, split into its area, group and serial numbers.
+///
+/// # Description
+///
+/// * Produced by [`validate_ssn`] or by parsing a string with [`str::parse`].
+/// * Displays in the dashed form `AAA-GG-SSSS`.
+/// * Orders by area, then group, then serial number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ssn {
+    area: u16,
+    group: u8,
+    serial: u16,
+}
+
+impl Ssn {
+    /// Creates an SSN from its parts, returning `None` if they do not form a valid SSN.
+    pub fn new(area: u16, group: u8, serial: u16) -> Option<Ssn> {
+        let valid = (1..=899).contains(&area) && area != 666 && (1..=99).contains(&group) && (1..=9999).contains(&serial);
+        (valid && !SAMPLE_SSNS.contains(&(area, group, serial))).then_some(Ssn { area, group, serial })
+    }
+
+    /// Returns the area number, the first three digits.
+    pub fn area(&self) -> u16 {
+        self.area
+    }
+
+    /// Returns the group number, the middle two digits.
+    pub fn group(&self) -> u8 {
+        self.group
+    }
+
+    /// Returns the serial number, the last four digits.
+    pub fn serial(&self) -> u16 {
+        self.serial
+    }
+
+    /// Returns the state the area number was assigned to; see [`ssn_issuing_state`].
+    pub fn issuing_state(&self) -> Option<&'static str> {
+        ssn_issuing_state(self.area)
+    }
+}
+
+/// The highest group number the Social Security Administration had issued in each area when it
+/// randomized SSN assignment on June 25, 2011.
+///
+/// # Description
+///
+/// * Before randomization, the groups of an area were issued in the order odd 01-09, even 10-98,
+///   even 02-08, then odd 11-99, so every group up to the high group in that order was issued.
+/// * The table is read from the high group list the SSA published, e.g. its final list of June
+///   2011, with [`HighGroupTable::parse`], or built with [`HighGroupTable::with_high_group`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HighGroupTable {
+    high_groups: HashMap<u16, u8>,
+}
+
+impl HighGroupTable {
+    /// Creates an empty table, in which no area was issued.
+    pub fn new() -> HighGroupTable {
+        HighGroupTable::default()
+    }
+
+    /// Sets the high group of `area` to `group`.
+    ///
+    /// # Panics
+    ///
+    /// * If `group` is not between 01 and 99, since group 00 was never issued.
+    pub fn with_high_group(mut self, area: u16, group: u8) -> HighGroupTable {
+        assert!((1..=99).contains(&group), "SSN group {} was never issued", group);
+        self.high_groups.insert(area, group);
+        self
+    }
+
+    /// Parses a high group list in the format the SSA published it.
+    ///
+    /// # Description
+    ///
+    /// * The list is a sequence of three-digit area numbers, each followed by its two-digit high
+    ///   group, separated by whitespace, e.g. `001 08  002 06  003 06`.
+    /// * The asterisks the SSA puts after groups that changed since the previous list are ignored,
+    ///   as are lines starting with `#`.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<HighGroupTable>` - Returns the table, or `None` if the list is not in that format.
+    pub fn parse(text: &str) -> Option<HighGroupTable> {
+        let mut tokens = text
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#'))
+            .flat_map(str::split_whitespace)
+            .map(|token| token.trim_end_matches('*'));
+        let mut table = HighGroupTable::new();
+        while let Some(area) = tokens.next() {
+            let group = tokens.next()?;
+            if area.len() != 3 || group.len() != 2 {
+                return None;
+            }
+            let (area, group) = (area.parse::<u16>().ok()?, group.parse::<u8>().ok()?);
+            if !(1..=899).contains(&area) || !(1..=99).contains(&group) {
+                return None;
+            }
+            table.high_groups.insert(area, group);
+        }
+        Some(table)
+    }
+
+    /// Returns the high group of `area`, or `None` if the area was not issued.
+    pub fn high_group(&self, area: u16) -> Option<u8> {
+        self.high_groups.get(&area).copied()
+    }
+
+    /// Returns whether `group` had been issued in `area`. Group 00 never was.
+    pub fn is_issued(&self, area: u16, group: u8) -> bool {
+        (1..=99).contains(&group)
+            && self.high_group(area).is_some_and(|high_group| group_rank(group) <= group_rank(high_group))
+    }
+}
+
+/// Returns the position of `group`, in 01-99, in the order groups were issued before 2011.
+fn group_rank(group: u8) -> u8 {
+    match (group % 2 == 1, group < 10) {
+        (true, true) => group / 2,
+        (false, false) => 5 + (group - 10) / 2,
+        (false, true) => 50 + (group - 2) / 2,
+        (true, false) => 54 + (group - 11) / 2,
+    }
+}
+
+/// Returns the state or territory the SSN area number `area` was assigned to before 2011.
+///
+/// # Description
+///
+/// * Read from a table bundled with the crate, compiled from the SSA's geographical number
+///   assignments. Area 232 was shared by North Carolina and West Virginia, and area 580 by
+///   Puerto Rico and the Virgin Islands.
+/// * Areas 700-728 were assigned by the Railroad Retirement Board until 1963, and 729-733 by
+///   the Enumeration at Entry program for immigrants.
+/// * SSNs issued since June 25, 2011 have random area numbers, which name no state.
+///
+/// # Returns
+///
+/// * `Option<&'static str>` - Returns the state, or `None` if the area was never assigned.
+pub fn ssn_issuing_state(area: u16) -> Option<&'static str> {
+    SSN_AREA_STATES.iter().find(|(first, last, _)| (*first..=*last).contains(&area)).map(|(_, _, state)| *state)
+}
+
+impl fmt::Display for Ssn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:03}-{:02}-{:04}", self.area, self.group, self.serial)
+    }
+}
+
+impl FromStr for Ssn {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Ssn, ValidationError> {
+        validate_ssn(s)
+    }
+}
+
+/// Validates a US Social Security Number (SSN).
+///
+/// # Arguments
+///
+/// * `ssn` - A string slice that holds the Social Security Number.
+///
+/// # Rules
+///
+/// * Accepts SSNs with or without dashes or spaces as separators.
+/// * Area, group, and serial numbers must not be 0.
+/// * Invalid area numbers: 666 and those in the range 900-999.
+/// * Well-known advertising and sample numbers are invalid: 078-05-1120, 219-09-9999, 123-45-6789
+///   and the SSNs made of one repeated digit, such as 111-11-1111.
+///
+/// # Returns
+///
+/// * `Result<Ssn, ValidationError>` - Returns the parsed [`Ssn`] if the SSN is valid, or the broken
+///   rule (an [`SsnError`]) and the span of the offending digits otherwise.
+pub fn validate_ssn(ssn: &str) -> Result<Ssn, ValidationError> {
+    validate_ssn_with(ssn, SeparatorMode::Lenient)
+}
+
+/// Validates a US Social Security Number (SSN), checking its separators as strictly as `mode` says.
+///
+/// # Arguments
+///
+/// * `ssn` - A string slice that holds the Social Security Number.
+/// * `mode` - How strictly to check the separators.
+///
+/// # Rules
+///
+/// * Every rule of [`validate_ssn`].
+/// * In [`SeparatorMode::Strict`], both separators must be dashes, both spaces, or both absent:
+///   `123-45-6788`, `123 45 6788` and `123456788` are valid, `123-45 6788` and `12345-6788` are not.
+///
+/// # Returns
+///
+/// * `Result<Ssn, ValidationError>` - Returns the parsed [`Ssn`] if the SSN is valid, or the broken
+///   rule (an [`SsnError`]) and the span of the offending characters otherwise.
+pub fn validate_ssn_with(ssn: &str, mode: SeparatorMode) -> Result<Ssn, ValidationError> {
+    // Check if the SSN matches the SSN_REGEX pattern.
+    // If it matches, the 'captures' variable will contain the matched components.
+    let Some(captures) = SSN_REGEX.captures(ssn) else {
+        return Err(ValidationError::new(SsnError::Malformed, Span::whole(ssn)));
+    };
+
+    let area_match = captures.name("area").unwrap();
+    let group_match = captures.name("group").unwrap();
+    let serial_match = captures.name("serial").unwrap();
+    let (Ok(area), Ok(group), Ok(serial)) =
+        (area_match.as_str().parse::<u16>(), group_match.as_str().parse::<u8>(), serial_match.as_str().parse::<u16>())
+    else {
+        return Err(ValidationError::new(SsnError::Malformed, Span::whole(ssn)));
+    };
+
+    // In strict mode, the separators must match; the span runs from the first to the second.
+    let first_separator = captures.name("first_separator").unwrap();
+    let second_separator = captures.name("second_separator").unwrap();
+    if mode == SeparatorMode::Strict && first_separator.as_str() != second_separator.as_str() {
+        return Err(ValidationError::new(SsnError::InconsistentSeparators, first_separator.start()..second_separator.end()));
+    }
+
+    // Area, group, and serial numbers must not be 0.
+    if area == 0 {
+        return Err(ValidationError::new(SsnError::ZeroArea, area_match));
+    }
+    if group == 0 {
+        return Err(ValidationError::new(SsnError::ZeroGroup, group_match));
+    }
+    if serial == 0 {
+        return Err(ValidationError::new(SsnError::ZeroSerial, serial_match));
+    }
+
+    // Invalid area numbers (specific): 666 ; (range): 900-999
+    if area == 666 || (area >= 900) {
+        return Err(ValidationError::new(SsnError::ForbiddenArea(area), area_match));
+    }
+
+    // Advertising and sample numbers were never validly issued to anyone.
+    if SAMPLE_SSNS.contains(&(area, group, serial)) {
+        return Err(ValidationError::new(SsnError::SampleNumber, Span::whole(ssn)));
+    }
+
+    // If the SSN passes all the validation checks, return its parts.
+    Ok(Ssn { area, group, serial })
+}
+
+/// Validates an SSN that must have been issued before the SSA randomized SSN assignment on June
+/// 25, 2011.
+///
+/// # Arguments
+///
+/// * `ssn` - A string slice that holds the Social Security Number.
+/// * `high_groups` - The high group list to check the area and group numbers against.
+///
+/// # Rules
+///
+/// * Every rule of [`validate_ssn`].
+/// * The area number must have been issued, i.e. have a high group in `high_groups`.
+/// * The group number must have been issued in its area, i.e. come no later than the area's high
+///   group in the issuance order of [`HighGroupTable`].
+///
+/// # Returns
+///
+/// * `Result<Ssn, ValidationError>` - Returns the parsed [`Ssn`] if the SSN is valid, or the broken
+///   rule (an [`SsnError`]) and the span of the offending digits otherwise.
+pub fn validate_ssn_pre_randomization(ssn: &str, high_groups: &HighGroupTable) -> Result<Ssn, ValidationError> {
+    let parsed = validate_ssn(ssn)?;
+    // validate_ssn only accepts input that SSN_REGEX matches.
+    let captures = SSN_REGEX.captures(ssn).unwrap();
+    if high_groups.high_group(parsed.area).is_none() {
+        return Err(ValidationError::new(SsnError::AreaNotIssued(parsed.area), captures.name("area").unwrap()));
+    }
+    if !high_groups.is_issued(parsed.area, parsed.group) {
+        let error = SsnError::GroupNotIssued { area: parsed.area, group: parsed.group };
+        return Err(ValidationError::new(error, captures.name("group").unwrap()));
+    }
+    Ok(parsed)
+}
+
+/// Validates a name in the format of a class roster.
+///
+/// # Arguments
+///
+/// * `name_roster` - A string slice that holds the name in the format "Last, First, MI".
+///
+/// # Rules
+///
+/// * Last name, followed by a comma and a space.
+/// * First name, followed by an optional comma and space, and middle initial(s).
+/// * Each part of the name should only contain alphabetic characters.
+///
+/// # Returns
+///
+/// * `Result<(), ValidationError>` - Returns `Ok(())` if the name is in the correct format, or
+///   the broken rule (a [`NameError`]) otherwise.
+pub fn validate_name_roster(name_roster: &str) -> Result<(), ValidationError> {
+    if NAME_ROSTER_REGEX.is_match(name_roster) {
+        return Ok(());
+    }
+    Err(diagnose_name_roster(name_roster))
+}
+
+/// Explains why `name_roster` does not match the roster format.
+fn diagnose_name_roster(name_roster: &str) -> ValidationError {
+    if !name_roster.contains(',') {
+        return ValidationError::new(NameError::MissingComma, Span::whole(name_roster));
+    }
+
+    let mut offset = 0;
+    for (index, part) in name_roster.split(',').enumerate() {
+        // Whitespace is allowed after each comma, but not before it.
+        let leading = if index == 0 { 0 } else { part.len() - part.trim_start().len() };
+        let start = offset + leading;
+        let end = offset + part.len();
+        let trimmed = &name_roster[start..end];
+        offset = end + 1;
+
+        if trimmed.is_empty() {
+            return ValidationError::new(NameError::EmptyPart, Span::new(start, end));
+        }
+        if let Some((at, ch)) = trimmed.char_indices().find(|(_, ch)| !ch.is_ascii_alphabetic()) {
+            return ValidationError::new(NameError::InvalidCharacter(ch), Span::new(start + at, start + at + ch.len_utf8()));
+        }
+        if index >= 2 && trimmed.len() > 1 {
+            return ValidationError::new(NameError::MiddleInitialTooLong, Span::new(start, end));
+        }
+    }
+
+    ValidationError::new(NameError::Malformed, Span::whole(name_roster))
+}
+
+/// The [`Validator`] for US Social Security Numbers; see [`validate_ssn`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SsnValidator;
+
+impl Validator for SsnValidator {
+    fn name(&self) -> &'static str {
+        "ssn"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "Nine digits, optionally separated as AAA-GG-SSSS by dashes or spaces.",
+            "Area, group, and serial numbers must not be 0.",
+            "Area numbers 666 and 900-999 are never issued.",
+            "Advertising and sample numbers such as 078-05-1120 and 123-45-6789 are invalid.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_ssn(input).map(|_| ())
+    }
+
+    /// Normalizes the SSN to the dashed form `AAA-GG-SSSS`.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        validate_ssn(input).map(|ssn| ssn.to_string())
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        let ssn = validate_ssn(input)?;
+        Ok(vec![
+            ("area", format!("{:03}", ssn.area())),
+            ("group", format!("{:02}", ssn.group())),
+            ("serial", format!("{:04}", ssn.serial())),
+        ])
+    }
+}
+
+/// The [`Validator`] for class roster names; see [`validate_name_roster`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NameRosterValidator;
+
+impl Validator for NameRosterValidator {
+    fn name(&self) -> &'static str {
+        "name"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "Last name, followed by a comma and a space.",
+            "First name, followed by an optional comma and space, and middle initial(s).",
+            "Each part of the name should only contain alphabetic characters.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_name_roster(input)
+    }
+
+    /// Normalizes the separators between name parts to a single comma and space.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        validate_name_roster(input)?;
+        Ok(input.split(',').map(str::trim_start).collect::<Vec<_>>().join(", "))
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        validate_name_roster(input)?;
+        let mut parts = input.split(',').map(str::trim_start);
+        let mut components = vec![("last", parts.next().unwrap().to_string()), ("first", parts.next().unwrap().to_string())];
+        components.extend(parts.map(|initial| ("middle", initial.to_string())));
+        Ok(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_validate_ssn() {
+        assert!(validate_ssn("001-01-0001").is_ok());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_dashes() {
+        assert!(validate_ssn("167-18-0009").is_ok());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_spaces() {
+        assert!(validate_ssn("123 01 6281").is_ok());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_dashes_and_spaces() {
+        assert!(validate_ssn("724-34 8124").is_ok());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_spaces_and_dashes() {
+        assert!(validate_ssn("123 45-6788").is_ok());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_invalid_area_number() {
+        assert!(validate_ssn("000-45-6789").is_err());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_invalid_group_number() {
+        assert!(validate_ssn("123-00-6789").is_err());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_invalid_serial_number() {
+        assert!(validate_ssn("123-45-0000").is_err());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_invalid_area_number_666() {
+        assert!(validate_ssn("666-45-6789").is_err());
+    }
+
+    #[test]
+    fn test_validate_ssn_with_invalid_area_number_900() {
+        assert!(validate_ssn("900-45-6789").is_err());
+    }
+
+    #[test]
+    fn test_validate_name_roster() {
+        assert!(validate_name_roster("Doe, John, W").is_ok());
+    }
+
+    #[test]
+    fn test_validate_name_roster_without_middle_initial() {
+        assert!(validate_name_roster("Ded, Zed").is_ok());
+    }
+
+    #[test]
+    fn test_validate_name_roster_with_multiple_middle_initial() {
+        assert!(validate_name_roster("Roe, Joe, W, H").is_ok());
+    }
+
+    #[test]
+    fn test_validate_name_roster_with_multiple_middle_initial_2() {
+        assert!(validate_name_roster("Roe, Joe, W, H, J").is_ok());
+    }
+
+    #[test]
+    fn test_validate_name_roster_with_multiple_middle_initial_3() {
+        assert!(validate_name_roster("Roe, Joe, W, H, J, K").is_ok());
+    }
+
+    #[test]
+    fn test_validate_name_roster_invalid() {
+        assert!(validate_name_roster("Roe Joe W H J K").is_err());
+    }
+
+    #[test]
+    fn test_validate_name_roster_invalid_2() {
+        assert!(validate_name_roster("Jingle Heimer Schmidt").is_err());
+    }
+
+    #[test]
+    fn test_validate_name_roster_invalid_with_dashes() {
+        assert!(validate_name_roster("Roe-Joe-W-H-J-K").is_err());
+    }
+
+    #[test]
+    fn test_validate_name_roster_invalid_with_underscores() {
+        assert!(validate_name_roster("Roe_Joe_W_H_J_K").is_err());
+    }
+
+    #[test]
+    fn test_validate_name_roster_invalid_with_semi_colons() {
+        assert!(validate_name_roster("Roe;Joe;W;H;J;K").is_err());
+    }
+
+    #[test]
+    fn test_validate_ssn_reports_forbidden_area() {
+        let err = validate_ssn("666-12-3456").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::ForbiddenArea(666)));
+        assert_eq!(err.span(), Span::new(0, 3));
+    }
+
+    #[test]
+    fn test_validate_ssn_reports_zero_group_span() {
+        let err = validate_ssn("123 00 6789").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::ZeroGroup));
+        assert_eq!(err.span(), Span::new(4, 6));
+    }
+
+    #[test]
+    fn test_validate_ssn_reports_malformed() {
+        let err = validate_ssn("12-345-6789").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::Malformed));
+    }
+
+    #[test]
+    fn test_validate_ssn_rejects_non_ascii_digits() {
+        for ssn in ["١٢٣-٤٥-٦٧٨٨", "１２３-４５-６７８８"] {
+            let err = validate_ssn(ssn).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::Malformed));
+        }
+    }
+
+    #[test]
+    fn test_validate_name_roster_reports_missing_comma() {
+        let err = validate_name_roster("Roe Joe W H J K").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Name(NameError::MissingComma));
+    }
+
+    #[test]
+    fn test_validate_name_roster_reports_invalid_character() {
+        let err = validate_name_roster("Roe, Jo3").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Name(NameError::InvalidCharacter('3')));
+        assert_eq!(err.span(), Span::new(7, 8));
+    }
+
+    #[test]
+    fn test_validate_name_roster_reports_long_middle_initial() {
+        let err = validate_name_roster("Doe, John, Wu").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Name(NameError::MiddleInitialTooLong));
+    }
+
+    #[test]
+    fn test_ssn_validator_normalizes_separators() {
+        assert_eq!(SsnValidator.normalize("724-34 8124").unwrap(), "724-34-8124");
+        assert_eq!(SsnValidator.normalize("167180009").unwrap(), "167-18-0009");
+    }
+
+    #[test]
+    fn test_ssn_validator_normalize_rejects_invalid() {
+        assert!(SsnValidator.normalize("000-45-6789").is_err());
+    }
+
+    #[test]
+    fn test_name_roster_validator_normalizes_separators() {
+        assert_eq!(NameRosterValidator.normalize("Roe,Joe,   W").unwrap(), "Roe, Joe, W");
+    }
+
+    #[test]
+    fn test_validate_ssn_returns_parts() {
+        let ssn = validate_ssn("724-34 8124").unwrap();
+        assert_eq!((ssn.area(), ssn.group(), ssn.serial()), (724, 34, 8124));
+    }
+
+    #[test]
+    fn test_ssn_display_round_trips() {
+        let ssn: Ssn = "001 01 0001".parse().unwrap();
+        assert_eq!(ssn.to_string(), "001-01-0001");
+        assert_eq!(ssn.to_string().parse::<Ssn>().unwrap(), ssn);
+    }
+
+    #[test]
+    fn test_ssn_orders_by_area_group_serial() {
+        let mut ssns: Vec<Ssn> = ["123-45-6788", "123-44-9999", "001-99-9999"].iter().map(|s| s.parse().unwrap()).collect();
+        ssns.sort();
+        let sorted: Vec<String> = ssns.iter().map(Ssn::to_string).collect();
+        assert_eq!(sorted, vec!["001-99-9999", "123-44-9999", "123-45-6788"]);
+    }
+
+    #[test]
+    fn test_ssn_new_rejects_invalid_parts() {
+        assert!(Ssn::new(666, 12, 3456).is_none());
+        assert!(Ssn::new(900, 12, 3456).is_none());
+        assert!(Ssn::new(123, 0, 3456).is_none());
+        assert!(Ssn::new(78, 5, 1120).is_none());
+        assert_eq!(Ssn::new(123, 45, 6788).unwrap().to_string(), "123-45-6788");
+    }
+
+    #[test]
+    fn test_ssn_validator_components() {
+        let components = SsnValidator.components("001 01 0001").unwrap();
+        assert_eq!(components, vec![("area", "001".to_string()), ("group", "01".to_string()), ("serial", "0001".to_string())]);
+    }
+
+    #[test]
+    fn test_name_roster_validator_components() {
+        let components = NameRosterValidator.components("Roe, Joe, W, H").unwrap();
+        let names: Vec<&str> = components.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["last", "first", "middle", "middle"]);
+        assert_eq!(components[1].1, "Joe");
+    }
+
+    #[test]
+    fn test_validate_ssn_with_sample_numbers() {
+        for ssn in ["078-05-1120", "219 09 9999", "123456789", "111-11-1111"] {
+            let err = validate_ssn(ssn).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::SampleNumber));
+            assert_eq!(err.span(), Span::whole(ssn));
+        }
+        assert!(validate_ssn("078-05-1121").is_ok());
+    }
+
+    #[test]
+    fn test_ssn_issuing_state() {
+        assert_eq!(ssn_issuing_state(1), Some("New Hampshire"));
+        assert_eq!(ssn_issuing_state(545), Some("California"));
+        assert_eq!(ssn_issuing_state(772), Some("Florida"));
+        assert_eq!(ssn_issuing_state(740), None);
+        assert_eq!(ssn_issuing_state(773), None);
+        assert_eq!(validate_ssn("078-05-1121").unwrap().issuing_state(), Some("New York"));
+    }
+
+    #[test]
+    fn test_group_rank_follows_issuance_order() {
+        let order: Vec<u8> = [1, 3, 5, 7, 9].into_iter().chain((10..=98).step_by(2)).chain([2, 4, 6, 8]).chain((11..=99).step_by(2)).collect();
+        assert_eq!(order.len(), 99);
+        assert!(order.iter().enumerate().all(|(rank, &group)| usize::from(group_rank(group)) == rank));
+    }
+
+    #[test]
+    fn test_validate_ssn_pre_randomization() {
+        let table = HighGroupTable::parse("# May 2011\n001 08  002 06*\n003 11").unwrap();
+        assert_eq!(table.high_group(2), Some(6));
+        assert!(validate_ssn_pre_randomization("001-98-0001", &table).is_ok());
+        assert!(validate_ssn_pre_randomization("003-02-0001", &table).is_ok());
+        let err = validate_ssn_pre_randomization("001-11-0001", &table).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::GroupNotIssued { area: 1, group: 11 }));
+        assert_eq!(err.span(), Span::new(4, 6));
+        let err = validate_ssn_pre_randomization("004-01-0001", &table).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::AreaNotIssued(4)));
+        assert_eq!(err.span(), Span::new(0, 3));
+        assert!(validate_ssn_pre_randomization("000-01-0001", &table).is_err());
+    }
+
+    #[test]
+    fn test_high_group_table_rejects_malformed_lists() {
+        assert!(HighGroupTable::parse("001 08 002").is_none());
+        assert!(HighGroupTable::parse("1 08").is_none());
+        assert!(HighGroupTable::parse("001 00").is_none());
+        assert!(HighGroupTable::parse("900 01").is_none());
+        assert_eq!(HighGroupTable::parse("").unwrap(), HighGroupTable::new());
+    }
+
+    #[test]
+    fn test_high_group_table_never_issued_group_zero() {
+        let table = HighGroupTable::new().with_high_group(1, 99);
+        assert!(!table.is_issued(1, 0));
+        assert!(!table.is_issued(2, 0));
+        assert!(table.is_issued(1, 99));
+    }
+
+    #[test]
+    #[should_panic(expected = "SSN group 0 was never issued")]
+    fn test_high_group_table_rejects_high_group_zero() {
+        HighGroupTable::new().with_high_group(1, 0);
+    }
+
+    #[test]
+    fn test_validate_ssn_with_strict_separators() {
+        for ssn in ["123-45-6788", "123 45 6788", "123456788"] {
+            assert!(validate_ssn_with(ssn, SeparatorMode::Strict).is_ok(), "{}", ssn);
+        }
+        for (ssn, span) in [("123-45 6788", Span::new(3, 7)), ("12345-6788", Span::new(3, 6)), ("123-456788", Span::new(3, 6))] {
+            assert!(validate_ssn_with(ssn, SeparatorMode::Lenient).is_ok(), "{}", ssn);
+            let err = validate_ssn_with(ssn, SeparatorMode::Strict).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::InconsistentSeparators));
+            assert_eq!(err.span(), span);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Internationalized domain names: conversion between the Unicode form of a domain, such as
+//! `bücher.de`, and its ASCII (punycode) form, `xn--bcher-kva.de`.
+//!
+//! Domains are processed with UTS #46 nontransitional processing, which maps and checks labels
+//! the way IDNA 2008 does: uppercase letters are lowercased, `ß` is kept rather than turned into
+//! `ss`, labels must not start or end with a hyphen or have one in both the third and fourth
+//! position, and the ASCII form must fit the DNS limits of 63 bytes per label and 253 in all.
+//!
+//! ```
+//! use regular_expressions::idn;
+//!
+//! assert_eq!(idn::domain_to_ascii("Bücher.de").as_deref(), Some("xn--bcher-kva.de"));
+//! assert_eq!(idn::domain_to_unicode("xn--bcher-kva.de").as_deref(), Some("bücher.de"));
+//! ```
+
+use idna::uts46::{AsciiDenyList, DnsLength, Hyphens, Uts46};
+
+/// Returns the ASCII form of `domain`, or `None` if it is not a valid internationalized domain name.
+pub fn domain_to_ascii(domain: &str) -> Option<String> {
+    Uts46::new()
+        .to_ascii(domain.as_bytes(), AsciiDenyList::STD3, Hyphens::Check, DnsLength::Verify)
+        .ok()
+        .map(|ascii| ascii.into_owned())
+}
+
+/// Returns the Unicode form of `domain`, decoding punycode labels, or `None` if it is not a
+/// valid internationalized domain name.
+pub fn domain_to_unicode(domain: &str) -> Option<String> {
+    // Converting to ASCII first applies the length checks, which to_unicode skips.
+    let ascii = domain_to_ascii(domain)?;
+    let (unicode, result) = Uts46::new().to_unicode(ascii.as_bytes(), AsciiDenyList::STD3, Hyphens::Check);
+    result.ok().map(|()| unicode.into_owned())
+}
+
+/// Returns whether `domain` contains a non-ASCII or punycode (`xn--`) label.
+pub fn is_internationalized(domain: &str) -> bool {
+    !domain.is_ascii() || domain.split('.').any(|label| label.get(..4).is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_to_ascii() {
+        assert_eq!(domain_to_ascii("bücher.de").as_deref(), Some("xn--bcher-kva.de"));
+        assert_eq!(domain_to_ascii("ПРИМЕР.рф").as_deref(), Some("xn--e1afmkfd.xn--p1ai"));
+        assert_eq!(domain_to_ascii("example.com").as_deref(), Some("example.com"));
+        assert_eq!(domain_to_ascii("faß.de").as_deref(), Some("xn--fa-hia.de"));
+    }
+
+    #[test]
+    fn test_domain_to_ascii_rejects_invalid_names() {
+        for domain in ["-bücher.de", "bü_cher.de", "ab--cd.de", "bücher..de", &format!("{}.de", "ü".repeat(64))] {
+            assert_eq!(domain_to_ascii(domain), None, "{}", domain);
+        }
+    }
+
+    #[test]
+    fn test_domain_to_unicode() {
+        assert_eq!(domain_to_unicode("xn--bcher-kva.de").as_deref(), Some("bücher.de"));
+        assert_eq!(domain_to_unicode("XN--BCHER-KVA.DE").as_deref(), Some("bücher.de"));
+        assert_eq!(domain_to_unicode("xn--zz.de"), None);
+    }
+
+    #[test]
+    fn test_is_internationalized() {
+        assert!(is_internationalized("bücher.de"));
+        assert!(is_internationalized("XN--bcher-kva.de"));
+        assert!(!is_internationalized("example.com"));
+    }
+}
@@ -0,0 +1,68 @@
+//! Regular-expression based validators for common US form fields.
+//!
+//! The validators are grouped by the kind of data they check:
+//!
+//! * [`identity`] - Social Security Numbers and class roster names.
+//! * [`taxpayer`] - US taxpayer identification numbers: SSNs, ITINs, ATINs and EINs.
+//! * [`contact`] - US and international phone numbers and email addresses.
+//! * [`mailbox`] - canonical keys of email addresses, for deduplicating accounts, and their
+//!   classification as disposable, role or free-mail addresses, and corrections of mistyped domains.
+//! * [`temporal`] - calendar dates in US and other common formats, leap years, constraints on dates
+//!   relative to today, card expiry dates and military time.
+//! * [`location`] - house addresses and city, state and ZIP code lines.
+//! * [`finance`] - US currency amounts.
+//! * [`web`] - URLs.
+//! * [`idn`] - conversion of internationalized domain names to and from punycode.
+//! * [`security`] - passwords.
+//! * [`text`] - free-text scanning for odd-length "ion" words.
+//! * [`scan`] - free-text scanning for every field kind, e.g. to locate PII in logs.
+//! * [`redact`] - masking of the fields found by [`scan`] in free text.
+//! * [`pseudonym`] - keyed, reversible replacement of SSNs and phone numbers with valid fakes.
+//! * [`generate`] - seeded random valid and invalid samples of every field kind, for fixtures.
+//!
+//! Validators return `Ok` when the input is valid and a [`ValidationError`] naming the broken
+//! rule and the offending span of the input otherwise.
+//!
+//! Validators that extract structured values return them typed: [`Ssn`], [`UsDate`],
+//! [`MilitaryTime`] and [`UsdAmount`] implement `FromStr`, `Display`, `Ord` and `Hash`, so
+//! downstream code never needs to re-parse the string.
+//!
+//! Each field kind also has a type implementing the common [`Validator`] trait, so callers can
+//! drive every check uniformly through `Box<dyn Validator>`; see [`validator`].
+//!
+//! Every validator is also re-exported from the crate root, so
+//! `regular_expressions::validate_ssn` and `regular_expressions::identity::validate_ssn`
+//! refer to the same function.
+
+pub mod contact;
+pub mod error;
+pub mod finance;
+pub mod generate;
+pub mod identity;
+pub mod idn;
+pub mod location;
+pub mod mailbox;
+pub mod pseudonym;
+pub mod redact;
+pub mod scan;
+pub mod security;
+pub mod taxpayer;
+pub mod temporal;
+pub mod text;
+pub mod validator;
+pub mod web;
+
+pub use contact::{
+    validate_email, validate_email_with, validate_international_email, validate_international_phone_number,
+    validate_phone_number, validate_phone_number_with,
+};
+pub use error::{ErrorKind, Span, ValidationError};
+pub use finance::{validate_currency, UsdAmount};
+pub use identity::{validate_name_roster, validate_ssn, validate_ssn_pre_randomization, validate_ssn_with, Ssn};
+pub use location::{validate_address, validate_city_state_zip};
+pub use security::validate_password;
+pub use taxpayer::{validate_taxpayer_id, TaxpayerId};
+pub use temporal::{is_leap_year, validate_card_expiry, validate_date, validate_military_time, MilitaryTime, UsDate};
+pub use text::validate_odd_ion_words;
+pub use validator::Validator;
+pub use web::{validate_international_url, validate_url};
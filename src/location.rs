@@ -0,0 +1,450 @@
+//! US postal locations: house addresses and city, state and ZIP code lines.
+
+use std::fmt;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::{Span, ValidationError};
+use crate::validator::Validator;
+
+/// Street types accepted at the end of a house address, in their full and abbreviated forms.
+pub(crate) const STREET_TYPES: [&str; 8] = ["road", "rd", "street", "st", "avenue", "ave", "boulevard", "blvd"];
+
+/// Two-letter USPS abbreviations of the 50 states.
+const STATE_ABBREVIATIONS: [&str; 50] = [
+    "AL", "AK", "AZ", "AR", "CA", "CO", "CT", "DE", "FL", "GA", "HI", "ID", "IL", "IN", "IA", "KS", "KY",
+    "LA", "ME", "MD", "MA", "MI", "MN", "MS", "MO", "MT", "NE", "NV", "NH", "NJ", "NM", "NY", "NC", "ND",
+    "OH", "OK", "OR", "PA", "RI", "SC", "SD", "TN", "TX", "UT", "VT", "VA", "WA", "WV", "WI", "WY",
+];
+
+/// Matches a house number, street name and street type.
+static ADDRESS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)^[0-9]+\s+([\w\s]+)\s+({})$", STREET_TYPES.join("|"))).unwrap()
+});
+
+/// Matches a "City, ST 12345" line, capturing the city, state and ZIP code.
+static CITY_STATE_ZIP_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    let states = STATE_ABBREVIATIONS.join("|");
+    Regex::new(&format!(r"^(?P<city>[a-zA-Z\s]+),\s+(?P<state>{})\s+(?P<zip>[0-9]{{5}}(-[0-9]{{4}})?)$", states)).unwrap()
+});
+
+/// Matches a 5-digit ZIP code with an optional 4-digit extension.
+static ZIP_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9]{5}(-[0-9]{4})?$").unwrap());
+
+/// The rule a house address broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressError {
+    /// The address does not start with a numeric house number.
+    MissingHouseNumber,
+    /// There is no street name between the house number and the street type.
+    MissingStreetName,
+    /// The address does not end in a known street type such as "Road" or "Ave".
+    UnknownStreetType(String),
+    /// The address does not match the expected format for another reason.
+    Malformed,
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressError::MissingHouseNumber => write!(f, "address must start with a house number"),
+            AddressError::MissingStreetName => write!(f, "address must contain a street name"),
+            AddressError::UnknownStreetType(found) => write!(f, "{:?} is not a known street type", found),
+            AddressError::Malformed => write!(f, "address must be in the form \"1234 Elm Street\""),
+        }
+    }
+}
+
+/// The rule a city, state, and ZIP code line broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CityStateZipError {
+    /// The city is not followed by a comma.
+    MissingComma,
+    /// The city contains characters other than letters and spaces.
+    InvalidCity,
+    /// The state is not a two-letter US state abbreviation.
+    UnknownState(String),
+    /// The ZIP code is not five digits, optionally followed by a hyphen and four digits.
+    InvalidZip,
+    /// The line does not match the expected format for another reason.
+    Malformed,
+}
+
+impl fmt::Display for CityStateZipError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CityStateZipError::MissingComma => write!(f, "city must be followed by a comma"),
+            CityStateZipError::InvalidCity => write!(f, "city may only contain letters and spaces"),
+            CityStateZipError::UnknownState(found) => write!(f, "{:?} is not a US state abbreviation", found),
+            CityStateZipError::InvalidZip => write!(f, "ZIP code must be 5 digits, optionally followed by -NNNN"),
+            CityStateZipError::Malformed => write!(f, "line must be in the form \"Seattle, WA 98101\""),
+        }
+    }
+}
+
+/// Validates an address by checking if it matches the expected format.
+///
+/// # Description
+///
+/// * This function checks if the input address matches a specific format, which consists of:
+///   - A house number (numeric)
+///   - Street name (alphanumeric)
+///   - Street type (e.g., road, street, avenue, etc.)
+/// * The input address string should be in a case-insensitive format.
+///
+/// # Arguments
+///
+/// * `address: &str` - The input address string to validate.
+///
+/// # Returns
+///
+/// * `Result<(), ValidationError>` - Returns `Ok(())` if the address matches the expected format, or the
+///   broken rule (an [`AddressError`]) otherwise.
+pub fn validate_address(address: &str) -> Result<(), ValidationError> {
+    if ADDRESS_REGEX.is_match(address) {
+        return Ok(());
+    }
+    Err(diagnose_address(address))
+}
+
+/// Explains why `address` does not match the address format.
+fn diagnose_address(address: &str) -> ValidationError {
+    let house_number_len = address.len() - address.trim_start_matches(|ch: char| ch.is_ascii_digit()).len();
+    if house_number_len == 0 {
+        let end = address.find(char::is_whitespace).unwrap_or(address.len());
+        return ValidationError::new(AddressError::MissingHouseNumber, Span::new(0, end));
+    }
+
+    let street_type_start =
+        address.char_indices().rfind(|(_, ch)| ch.is_whitespace()).map_or(0, |(i, ch)| i + ch.len_utf8());
+    let street_type = &address[street_type_start..];
+    if !STREET_TYPES.contains(&street_type.to_lowercase().as_str()) {
+        return ValidationError::new(
+            AddressError::UnknownStreetType(street_type.to_string()),
+            Span::new(street_type_start, address.len()),
+        );
+    }
+
+    if address[house_number_len..street_type_start].trim().is_empty() {
+        return ValidationError::new(AddressError::MissingStreetName, Span::new(house_number_len, street_type_start));
+    }
+
+    ValidationError::new(AddressError::Malformed, Span::whole(address))
+}
+
+/// Validates a city, state, and ZIP code combination by checking if it matches the expected format.
+///
+/// # Description
+///
+/// * This function checks if the input string matches the specific format, which consists of:
+///   - City name (alphabetical characters and spaces)
+///   - State abbreviation (2-letter US state abbreviation)
+///   - ZIP code (5-digit ZIP code, optionally followed by a hyphen and 4 more digits)
+/// * The input string should have a comma and a space separating the city and state, and a space separating the state and ZIP code.
+///
+/// # Arguments
+///
+/// * `input: &str` - The input string containing the city, state, and ZIP code to validate.
+///
+/// # Returns
+///
+/// * `Result<(), ValidationError>` - Returns `Ok(())` if the input matches the expected format, or the
+///   broken rule (a [`CityStateZipError`]) otherwise.
+pub fn validate_city_state_zip(input: &str) -> Result<(), ValidationError> {
+    if CITY_STATE_ZIP_REGEX.is_match(input) {
+        return Ok(());
+    }
+    Err(diagnose_city_state_zip(input))
+}
+
+/// Explains why `input` does not match the city, state, and ZIP code format.
+fn diagnose_city_state_zip(input: &str) -> ValidationError {
+    let Some(comma) = input.find(',') else {
+        return ValidationError::new(CityStateZipError::MissingComma, Span::whole(input));
+    };
+
+    let city = &input[..comma];
+    if city.is_empty() || !city.chars().all(|ch| ch.is_ascii_alphabetic() || ch.is_whitespace()) {
+        return ValidationError::new(CityStateZipError::InvalidCity, Span::new(0, comma));
+    }
+
+    // The state and ZIP code are the whitespace separated words after the comma.
+    let rest_start = comma + 1;
+    let mut words = Vec::new();
+    let mut word_start = None;
+    for (i, ch) in input[rest_start..].char_indices().chain([(input.len() - rest_start, ' ')]) {
+        match (ch.is_whitespace(), word_start) {
+            (false, None) => word_start = Some(i),
+            (true, Some(start)) => {
+                words.push(Span::new(rest_start + start, rest_start + i));
+                word_start = None;
+            }
+            _ => {}
+        }
+    }
+
+    if let [state, zip] = words[..] {
+        if !STATE_ABBREVIATIONS.contains(&state.slice(input)) {
+            return ValidationError::new(CityStateZipError::UnknownState(state.slice(input).to_string()), state);
+        }
+        if !ZIP_REGEX.is_match(zip.slice(input)) {
+            return ValidationError::new(CityStateZipError::InvalidZip, zip);
+        }
+    }
+
+    ValidationError::new(CityStateZipError::Malformed, Span::whole(input))
+}
+
+/// The [`Validator`] for house addresses; see [`validate_address`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AddressValidator;
+
+impl Validator for AddressValidator {
+    fn name(&self) -> &'static str {
+        "address"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "A numeric house number.",
+            "A street name of letters, digits and spaces.",
+            "A street type: road, rd, street, st, avenue, ave, boulevard or blvd, in any case.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_address(input)
+    }
+
+    /// Collapses runs of whitespace to single spaces.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        validate_address(input)?;
+        Ok(input.split_whitespace().collect::<Vec<_>>().join(" "))
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        validate_address(input)?;
+        let words: Vec<&str> = input.split_whitespace().collect();
+        Ok(vec![
+            ("number", words[0].to_string()),
+            ("street", words[1..words.len() - 1].join(" ")),
+            ("type", words[words.len() - 1].to_string()),
+        ])
+    }
+}
+
+/// The [`Validator`] for city, state, and ZIP code lines; see [`validate_city_state_zip`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CityStateZipValidator;
+
+impl Validator for CityStateZipValidator {
+    fn name(&self) -> &'static str {
+        "city-state-zip"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "A city name of letters and spaces, followed by a comma and whitespace.",
+            "A two-letter US state abbreviation, followed by whitespace.",
+            "A 5-digit ZIP code, optionally followed by a hyphen and 4 more digits.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_city_state_zip(input)
+    }
+
+    /// Normalizes the line to `City, ST 12345`, collapsing runs of whitespace.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        validate_city_state_zip(input)?;
+        let (city, rest) = input.split_once(',').unwrap();
+        let city = city.split_whitespace().collect::<Vec<_>>().join(" ");
+        let rest = rest.split_whitespace().collect::<Vec<_>>().join(" ");
+        Ok(format!("{}, {}", city, rest))
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        validate_city_state_zip(input)?;
+        let captures = CITY_STATE_ZIP_REGEX.captures(input).unwrap();
+        Ok(["city", "state", "zip"].iter().map(|name| (*name, captures[*name].trim().to_string())).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_validate_address_valid_full_road_name() {
+        assert!(validate_address("1234 Elmwood Road").is_ok());
+    }
+
+    #[test]
+    fn test_validate_address_valid_abbreviated_street_name() {
+        assert!(validate_address("5678 Oak St").is_ok());
+    }
+
+    #[test]
+    fn test_validate_address_valid_abbreviated_avenue_name() {
+        assert!(validate_address("9012 Maple Ave").is_ok());
+    }
+
+    #[test]
+    fn test_validate_address_valid_full_boulevard_name() {
+        assert!(validate_address("3456 Cherry Boulevard").is_ok());
+    }
+
+    #[test]
+    fn test_validate_address_valid_abbreviated_boulevard_name() {
+        assert!(validate_address("7890 Pine Blvd").is_ok());
+    }
+
+    #[test]
+    fn test_validate_address_invalid_no_street_number() {
+        assert!(validate_address("Cedar Road").is_err());
+    }
+
+    #[test]
+    fn test_validate_address_invalid_no_road_type() {
+        assert!(validate_address("1234 Walnut").is_err());
+    }
+
+    #[test]
+    fn test_validate_address_invalid_incorrect_road_abbreviation() {
+        assert!(validate_address("5678 Elm R").is_err());
+    }
+
+    #[test]
+    fn test_validate_address_invalid_missing_space() {
+        assert!(validate_address("9012Birch Street").is_err());
+    }
+
+    #[test]
+    fn test_validate_address_invalid_street_name_missing_space() {
+        assert!(validate_address("3456 PeachAve").is_err());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_valid_city_name() {
+        assert!(validate_city_state_zip("Seattle, WA 98101").is_ok());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_valid_state_abbreviation() {
+        assert!(validate_city_state_zip("Austin, TX 78701").is_ok());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_valid_zip_code() {
+        assert!(validate_city_state_zip("New York, NY 10001").is_ok());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_valid_with_spaces() {
+        assert!(validate_city_state_zip("Los Angeles,   CA    90001").is_ok());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_valid_multiline() {
+        assert!(validate_city_state_zip("Portland,\nOR 97201").is_ok());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_invalid_no_comma() {
+        assert!(validate_city_state_zip("Boston MA 02101").is_err());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_invalid_no_space_after_comma() {
+        assert!(validate_city_state_zip("Chicago,IL 60601").is_err());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_invalid_wrong_state_abbreviation() {
+        assert!(validate_city_state_zip("Miami, FLA 33101").is_err());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_invalid_non_alphabetic_city_name() {
+        assert!(validate_city_state_zip("123City, CA 90001").is_err());
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_invalid_zip_code_length() {
+        assert!(validate_city_state_zip("Denver, CO 8020").is_err());
+    }
+
+    #[test]
+    fn test_validate_address_reports_missing_house_number() {
+        let err = validate_address("Cedar Road").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Address(AddressError::MissingHouseNumber));
+        assert_eq!(err.span(), Span::new(0, 5));
+    }
+
+    #[test]
+    fn test_validate_address_rejects_non_ascii_house_number() {
+        let err = validate_address("١٢٣٤ Elm Street").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Address(AddressError::MissingHouseNumber));
+    }
+
+    #[test]
+    fn test_validate_address_reports_unknown_street_type() {
+        let err = validate_address("5678 Elm R").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Address(AddressError::UnknownStreetType("R".to_string())));
+        assert_eq!(err.span(), Span::new(9, 10));
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_reports_unknown_state() {
+        let err = validate_city_state_zip("Miami, FLA 33101").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::CityStateZip(CityStateZipError::UnknownState("FLA".to_string())));
+        assert_eq!(err.span(), Span::new(7, 10));
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_reports_invalid_zip() {
+        let err = validate_city_state_zip("Denver, CO 8020").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::CityStateZip(CityStateZipError::InvalidZip));
+        assert_eq!(err.span(), Span::new(11, 15));
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_rejects_non_ascii_zip() {
+        let err = validate_city_state_zip("Seattle, WA ٩٨١٠١").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::CityStateZip(CityStateZipError::InvalidZip));
+    }
+
+    #[test]
+    fn test_validate_city_state_zip_reports_missing_comma() {
+        let err = validate_city_state_zip("Boston MA 02101").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::CityStateZip(CityStateZipError::MissingComma));
+    }
+
+    #[test]
+    fn test_address_validator_collapses_whitespace() {
+        assert_eq!(AddressValidator.normalize("1234   Elmwood  Road").unwrap(), "1234 Elmwood Road");
+    }
+
+    #[test]
+    fn test_city_state_zip_validator_normalizes_spacing() {
+        assert_eq!(CityStateZipValidator.normalize("Los Angeles,   CA    90001").unwrap(), "Los Angeles, CA 90001");
+        assert_eq!(CityStateZipValidator.normalize("Portland,\nOR 97201").unwrap(), "Portland, OR 97201");
+    }
+
+    #[test]
+    fn test_address_validator_components() {
+        let components = AddressValidator.components("3456 Cherry Tree Boulevard").unwrap();
+        assert_eq!(
+            components,
+            vec![("number", "3456".to_string()), ("street", "Cherry Tree".to_string()), ("type", "Boulevard".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_city_state_zip_validator_components() {
+        let components = CityStateZipValidator.components("New York, NY 10001").unwrap();
+        assert_eq!(components, vec![("city", "New York".to_string()), ("state", "NY".to_string()), ("zip", "10001".to_string())]);
+    }
+}
@@ -0,0 +1,695 @@
+//! Email address identity: canonical keys that let accounts registered with different spellings
+//! of the same mailbox be deduplicated, offline classification of addresses as disposable, role or
+//! free-mail accounts, and suggestions for mistyped domains.
+//!
+//! ```
+//! use regular_expressions::mailbox::EmailNormalizer;
+//!
+//! let normalizer = EmailNormalizer::with_known_providers();
+//! let email = normalizer.normalize("John.Doe+newsletter@GoogleMail.com").unwrap();
+//! assert_eq!(email.canonical_key(), "johndoe@gmail.com");
+//! assert_eq!(email.subaddress(), Some("newsletter"));
+//!
+//! let classification = regular_expressions::mailbox::classify_email("noreply@mailinator.com").unwrap();
+//! assert!(classification.is_disposable() && classification.is_role_account());
+//!
+//! let suggestions = regular_expressions::mailbox::suggest_email("jo@gmial.con").unwrap();
+//! assert_eq!(suggestions[0].address(), "jo@gmail.com");
+//! ```
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use crate::contact::{validate_international_email, EmailLevel, InternationalEmail};
+use crate::error::ValidationError;
+
+/// The bundled list of disposable email domains.
+const DISPOSABLE_DOMAINS: &str = include_str!("../data/disposable_domains.txt");
+
+/// The bundled list of free-mail domains.
+const FREE_MAIL_DOMAINS: &str = include_str!("../data/free_mail_domains.txt");
+
+/// The bundled list of role account prefixes.
+const ROLE_ACCOUNTS: &str = include_str!("../data/role_accounts.txt");
+
+/// The bundled list of common email domains, most common first.
+const COMMON_DOMAINS: &str = include_str!("../data/common_domains.txt");
+
+/// The bundled list of common top-level domains, most common first.
+const COMMON_TLDS: &str = include_str!("../data/common_tlds.txt");
+
+/// The classifier used by [`classify_email`].
+static CLASSIFIER: LazyLock<EmailClassifier> = LazyLock::new(EmailClassifier::new);
+
+/// The suggester used by [`suggest_email`].
+static SUGGESTER: LazyLock<EmailSuggester> = LazyLock::new(EmailSuggester::new);
+
+/// How one mail provider reads the prefix of the addresses it hosts.
+///
+/// # Description
+///
+/// * A rule applies to every domain given to [`ProviderRule::new`], and can map them all to one
+///   canonical domain, e.g. `googlemail.com` to `gmail.com`.
+/// * By default a rule changes nothing; each builder method adds one way in which the provider
+///   delivers differently spelled prefixes to the same mailbox.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProviderRule {
+    domains: Vec<String>,
+    canonical_domain: Option<String>,
+    subaddress_separator: Option<char>,
+    ignore_dots: bool,
+    case_insensitive: bool,
+}
+
+impl ProviderRule {
+    /// Creates a rule for addresses at any of `domains`, matched case-insensitively.
+    pub fn new<S: AsRef<str>>(domains: impl IntoIterator<Item = S>) -> ProviderRule {
+        ProviderRule {
+            domains: domains.into_iter().map(|domain| domain.as_ref().to_ascii_lowercase()).collect(),
+            canonical_domain: None,
+            subaddress_separator: None,
+            ignore_dots: false,
+            case_insensitive: false,
+        }
+    }
+
+    /// Replaces each of the rule's domains with `domain` in canonical keys.
+    pub fn canonical_domain(mut self, domain: &str) -> ProviderRule {
+        self.canonical_domain = Some(domain.to_ascii_lowercase());
+        self
+    }
+
+    /// Drops everything from the first `separator` in the prefix, e.g. the `+news` of `jo+news`.
+    pub fn subaddress_separator(mut self, separator: char) -> ProviderRule {
+        self.subaddress_separator = Some(separator);
+        self
+    }
+
+    /// Drops the dots of the prefix, so `j.o.doe` and `jodoe` are one mailbox.
+    pub fn ignore_dots(mut self) -> ProviderRule {
+        self.ignore_dots = true;
+        self
+    }
+
+    /// Lowercases the prefix, so `JoDoe` and `jodoe` are one mailbox.
+    pub fn case_insensitive(mut self) -> ProviderRule {
+        self.case_insensitive = true;
+        self
+    }
+
+    /// Returns the domains the rule applies to, lowercased.
+    pub fn domains(&self) -> &[String] {
+        &self.domains
+    }
+
+    /// Returns whether the rule applies to `domain`, an ASCII domain.
+    fn applies_to(&self, domain: &str) -> bool {
+        self.domains.iter().any(|candidate| candidate.eq_ignore_ascii_case(domain))
+    }
+}
+
+/// Returns the rules of the large mail providers whose behavior is publicly documented.
+///
+/// # Description
+///
+/// * Gmail ignores dots and `+tags` and treats `googlemail.com` as `gmail.com`.
+/// * Outlook.com, iCloud, Fastmail and Proton Mail ignore `+tags`; Yahoo Mail ignores `-tags`.
+/// * Every listed provider treats prefixes case-insensitively.
+pub fn known_provider_rules() -> Vec<ProviderRule> {
+    vec![
+        ProviderRule::new(["gmail.com", "googlemail.com"])
+            .canonical_domain("gmail.com")
+            .subaddress_separator('+')
+            .ignore_dots()
+            .case_insensitive(),
+        ProviderRule::new(["outlook.com", "hotmail.com", "live.com", "msn.com"]).subaddress_separator('+').case_insensitive(),
+        ProviderRule::new(["yahoo.com", "ymail.com", "rocketmail.com"]).subaddress_separator('-').case_insensitive(),
+        ProviderRule::new(["icloud.com", "me.com", "mac.com"])
+            .canonical_domain("icloud.com")
+            .subaddress_separator('+')
+            .case_insensitive(),
+        ProviderRule::new(["fastmail.com", "fastmail.fm"]).subaddress_separator('+').case_insensitive(),
+        ProviderRule::new(["proton.me", "protonmail.com", "protonmail.ch", "pm.me"])
+            .canonical_domain("proton.me")
+            .subaddress_separator('+')
+            .case_insensitive(),
+    ]
+}
+
+/// An email address together with the canonical key of its mailbox.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NormalizedEmail {
+    original: String,
+    prefix: String,
+    domain: String,
+    subaddress: Option<String>,
+    canonical_key: String,
+}
+
+impl NormalizedEmail {
+    /// Returns the address as given.
+    pub fn original(&self) -> &str {
+        &self.original
+    }
+
+    /// Returns the prefix before the @, as written.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the domain after the @, lowercased and in its Unicode form.
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    /// Returns the subaddress the provider rule dropped from the prefix, e.g. `news` for `jo+news`.
+    pub fn subaddress(&self) -> Option<&str> {
+        self.subaddress.as_deref()
+    }
+
+    /// Returns the key shared by every spelling of the same mailbox, e.g. `johndoe@gmail.com` for
+    /// `John.Doe+news@googlemail.com`. Its domain is in ASCII form.
+    pub fn canonical_key(&self) -> &str {
+        &self.canonical_key
+    }
+}
+
+/// Computes canonical keys of email addresses.
+///
+/// # Description
+///
+/// * Addresses are validated with [`validate_international_email`] at the level set with
+///   [`EmailNormalizer::with_level`], the project rules by default.
+/// * Every canonical key has its domain lowercased and in ASCII form, so `jo@Bücher.de` and
+///   `jo@xn--bcher-kva.de` share a key.
+/// * The first [`ProviderRule`] that applies to the domain then rewrites the prefix. Prefixes at
+///   domains without a rule are kept as written, since RFC 5321 lets each server decide how to
+///   read them.
+#[derive(Debug, Clone, Default)]
+pub struct EmailNormalizer {
+    rules: Vec<ProviderRule>,
+    level: EmailLevel,
+}
+
+impl EmailNormalizer {
+    /// Creates a normalizer without provider rules, which only lowercases domains.
+    pub fn new() -> EmailNormalizer {
+        EmailNormalizer::default()
+    }
+
+    /// Creates a normalizer with the rules of [`known_provider_rules`].
+    pub fn with_known_providers() -> EmailNormalizer {
+        EmailNormalizer { rules: known_provider_rules(), ..EmailNormalizer::default() }
+    }
+
+    /// Adds `rule`, which takes precedence over the rules added before it.
+    pub fn with_rule(mut self, rule: ProviderRule) -> EmailNormalizer {
+        self.rules.insert(0, rule);
+        self
+    }
+
+    /// Sets the strictness level addresses are validated at.
+    pub fn with_level(mut self, level: EmailLevel) -> EmailNormalizer {
+        self.level = level;
+        self
+    }
+
+    /// Returns the provider rules, in order of precedence.
+    pub fn rules(&self) -> &[ProviderRule] {
+        &self.rules
+    }
+
+    /// Validates `email` and computes its canonical key.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<NormalizedEmail, ValidationError>` - Returns the address with its canonical key if it
+    ///   is valid, or the broken rule (an [`EmailError`](crate::contact::EmailError)) otherwise.
+    pub fn normalize(&self, email: &str) -> Result<NormalizedEmail, ValidationError> {
+        let address = validate_international_email(email, self.level)?;
+        let mut prefix = address.prefix().to_string();
+        let mut domain = address.ascii_domain().to_string();
+        let mut subaddress = None;
+
+        if let Some(rule) = self.rules.iter().find(|rule| rule.applies_to(&domain)) {
+            if let Some((base, tag)) = rule.subaddress_separator.and_then(|separator| prefix.split_once(separator)) {
+                subaddress = Some(tag.to_string());
+                prefix = base.to_string();
+            }
+            if rule.ignore_dots {
+                prefix.retain(|ch| ch != '.');
+            }
+            if rule.case_insensitive {
+                prefix = prefix.to_lowercase();
+            }
+            if let Some(canonical) = &rule.canonical_domain {
+                domain.clone_from(canonical);
+            }
+        }
+
+        Ok(NormalizedEmail {
+            original: email.to_string(),
+            prefix: address.prefix().to_string(),
+            domain: address.unicode_domain().to_string(),
+            subaddress,
+            canonical_key: format!("{}@{}", prefix, domain),
+        })
+    }
+}
+
+/// Returns the entries of a list in the format of the bundled lists: one entry per line, with
+/// blank lines and lines starting with `#` skipped.
+pub fn parse_list(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// What kind of account an email address belongs to; see [`EmailClassifier`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailClassification {
+    email: InternationalEmail,
+    disposable: bool,
+    role_account: bool,
+    free_mail: bool,
+}
+
+impl EmailClassification {
+    /// Returns the validated address.
+    pub fn email(&self) -> &InternationalEmail {
+        &self.email
+    }
+
+    /// Returns whether the domain belongs to a disposable (throwaway) email provider.
+    pub fn is_disposable(&self) -> bool {
+        self.disposable
+    }
+
+    /// Returns whether the prefix names a role, such as `admin` or `noreply`, rather than a person.
+    pub fn is_role_account(&self) -> bool {
+        self.role_account
+    }
+
+    /// Returns whether the domain belongs to a free consumer email provider such as Gmail.
+    pub fn is_free_mail(&self) -> bool {
+        self.free_mail
+    }
+}
+
+/// Classifies email addresses against lists of disposable domains, free-mail domains and role
+/// account prefixes, without any network access.
+///
+/// # Description
+///
+/// * [`EmailClassifier::new`] uses the lists bundled in the crate's `data` directory. Entries from
+///   updated lists can be added with the `with_*` methods, e.g. from a file read with [`parse_list`].
+/// * Domains match themselves and their subdomains, so `mailinator.com` also lists
+///   `eu.mailinator.com`. Internationalized domains are matched in their ASCII form.
+/// * Prefixes match case-insensitively and ignore any `+tag`, so `Admin+alerts` is a role account.
+#[derive(Debug, Clone)]
+pub struct EmailClassifier {
+    disposable_domains: HashSet<String>,
+    free_mail_domains: HashSet<String>,
+    role_accounts: HashSet<String>,
+    level: EmailLevel,
+}
+
+impl EmailClassifier {
+    /// Creates a classifier with the bundled lists.
+    pub fn new() -> EmailClassifier {
+        EmailClassifier::empty()
+            .with_disposable_domains(parse_list(DISPOSABLE_DOMAINS))
+            .with_free_mail_domains(parse_list(FREE_MAIL_DOMAINS))
+            .with_role_accounts(parse_list(ROLE_ACCOUNTS))
+    }
+
+    /// Creates a classifier with empty lists.
+    pub fn empty() -> EmailClassifier {
+        EmailClassifier {
+            disposable_domains: HashSet::new(),
+            free_mail_domains: HashSet::new(),
+            role_accounts: HashSet::new(),
+            level: EmailLevel::default(),
+        }
+    }
+
+    /// Adds `domains` to the disposable domains.
+    pub fn with_disposable_domains<S: AsRef<str>>(mut self, domains: impl IntoIterator<Item = S>) -> EmailClassifier {
+        self.disposable_domains.extend(domains.into_iter().map(|domain| domain.as_ref().to_ascii_lowercase()));
+        self
+    }
+
+    /// Adds `domains` to the free-mail domains.
+    pub fn with_free_mail_domains<S: AsRef<str>>(mut self, domains: impl IntoIterator<Item = S>) -> EmailClassifier {
+        self.free_mail_domains.extend(domains.into_iter().map(|domain| domain.as_ref().to_ascii_lowercase()));
+        self
+    }
+
+    /// Adds `prefixes` to the role account prefixes.
+    pub fn with_role_accounts<S: AsRef<str>>(mut self, prefixes: impl IntoIterator<Item = S>) -> EmailClassifier {
+        self.role_accounts.extend(prefixes.into_iter().map(|prefix| prefix.as_ref().to_lowercase()));
+        self
+    }
+
+    /// Sets the strictness level addresses are validated at.
+    pub fn with_level(mut self, level: EmailLevel) -> EmailClassifier {
+        self.level = level;
+        self
+    }
+
+    /// Validates `email` and classifies it.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<EmailClassification, ValidationError>` - Returns the flags of the address if it is
+    ///   valid, or the broken rule (an [`EmailError`](crate::contact::EmailError)) otherwise.
+    pub fn classify(&self, email: &str) -> Result<EmailClassification, ValidationError> {
+        let email = validate_international_email(email, self.level)?;
+        let domain = email.ascii_domain();
+        let base = email.prefix().split('+').next().unwrap_or_default().to_lowercase();
+        Ok(EmailClassification {
+            disposable: lists_domain(&self.disposable_domains, domain),
+            role_account: self.role_accounts.contains(&base),
+            free_mail: lists_domain(&self.free_mail_domains, domain),
+            email,
+        })
+    }
+}
+
+/// Creates a classifier with the bundled lists.
+impl Default for EmailClassifier {
+    fn default() -> EmailClassifier {
+        EmailClassifier::new()
+    }
+}
+
+/// Returns whether `domain` or one of its parent domains is in `domains`.
+fn lists_domain(domains: &HashSet<String>, domain: &str) -> bool {
+    let mut rest = domain;
+    loop {
+        if domains.contains(rest) {
+            return true;
+        }
+        match rest.split_once('.') {
+            Some((_, parent)) => rest = parent,
+            None => return false,
+        }
+    }
+}
+
+/// Validates `email` and classifies it against the bundled lists; see [`EmailClassifier`].
+pub fn classify_email(email: &str) -> Result<EmailClassification, ValidationError> {
+    CLASSIFIER.classify(email)
+}
+
+/// A corrected spelling of a mistyped email address.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailSuggestion {
+    address: String,
+    domain: String,
+    distance: usize,
+}
+
+impl EmailSuggestion {
+    /// Returns the corrected address, e.g. `jo@gmail.com` for `jo@gmial.com`.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Returns the corrected domain, in ASCII form.
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    /// Returns the number of edits between the mistyped domain and [`EmailSuggestion::domain`].
+    pub fn distance(&self) -> usize {
+        self.distance
+    }
+}
+
+/// Suggests corrections of email addresses whose domain is probably mistyped, for prompts such as
+/// "did you mean jo@gmail.com?".
+///
+/// # Description
+///
+/// * A domain that is not itself common is compared with each common domain; those within
+///   [`EmailSuggester::with_max_distance`] edits, 2 by default, are suggested.
+/// * A top-level domain that is not common is compared with each common top-level domain; those
+///   one edit away are suggested with the rest of the domain kept, so `example.con` gives
+///   `example.com`.
+/// * Edits are insertions, deletions, substitutions and swaps of adjacent characters.
+/// * Common domains are suggested before top-level domain corrections, since they are known to
+///   receive mail. Each kind is ranked by distance, then by the order of its list, which the
+///   bundled lists keep most common first.
+#[derive(Debug, Clone)]
+pub struct EmailSuggester {
+    domains: Vec<String>,
+    tlds: Vec<String>,
+    max_distance: usize,
+    level: EmailLevel,
+}
+
+impl EmailSuggester {
+    /// Creates a suggester with the bundled lists of common domains and top-level domains.
+    pub fn new() -> EmailSuggester {
+        EmailSuggester {
+            domains: parse_list(COMMON_DOMAINS).map(str::to_string).collect(),
+            tlds: parse_list(COMMON_TLDS).map(str::to_string).collect(),
+            max_distance: 2,
+            level: EmailLevel::default(),
+        }
+    }
+
+    /// Replaces the common domains with `domains`, most common first.
+    pub fn with_domains<S: AsRef<str>>(mut self, domains: impl IntoIterator<Item = S>) -> EmailSuggester {
+        self.domains = domains.into_iter().map(|domain| domain.as_ref().to_ascii_lowercase()).collect();
+        self
+    }
+
+    /// Replaces the common top-level domains with `tlds`, most common first.
+    pub fn with_tlds<S: AsRef<str>>(mut self, tlds: impl IntoIterator<Item = S>) -> EmailSuggester {
+        self.tlds = tlds.into_iter().map(|tld| tld.as_ref().to_ascii_lowercase()).collect();
+        self
+    }
+
+    /// Sets the most edits a domain may be away from a common domain to be suggested.
+    pub fn with_max_distance(mut self, max_distance: usize) -> EmailSuggester {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Sets the strictness level addresses are validated at.
+    pub fn with_level(mut self, level: EmailLevel) -> EmailSuggester {
+        self.level = level;
+        self
+    }
+
+    /// Validates `email` and suggests corrections of its domain.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<EmailSuggestion>, ValidationError>` - Returns the ranked suggestions, empty if
+    ///   the domain looks right, if the address is valid, or the broken rule (an
+    ///   [`EmailError`](crate::contact::EmailError)) otherwise.
+    pub fn suggest(&self, email: &str) -> Result<Vec<EmailSuggestion>, ValidationError> {
+        let email = validate_international_email(email, self.level)?;
+        let domain = email.ascii_domain().to_ascii_lowercase();
+        if domain.starts_with('[') || self.domains.contains(&domain) {
+            return Ok(Vec::new());
+        }
+        let mut candidates: Vec<(usize, String)> = self
+            .domains
+            .iter()
+            .map(|common| (edit_distance(&domain, common), common.clone()))
+            .filter(|(distance, _)| *distance <= self.max_distance)
+            .collect();
+        // The sort is stable, so candidates at the same distance keep the order of the list.
+        candidates.sort_by_key(|(distance, _)| *distance);
+        if let Some((name, tld)) = domain.rsplit_once('.') {
+            if !self.tlds.iter().any(|common| common == tld) {
+                candidates.extend(
+                    self.tlds
+                        .iter()
+                        .filter(|common| edit_distance(tld, common) == 1)
+                        .map(|common| (1, format!("{}.{}", name, common))),
+                );
+            }
+        }
+        let mut suggestions: Vec<EmailSuggestion> = Vec::new();
+        for (distance, domain) in candidates {
+            if suggestions.iter().all(|suggestion| suggestion.domain != domain) {
+                suggestions.push(EmailSuggestion { address: format!("{}@{}", email.prefix(), domain), domain, distance });
+            }
+        }
+        Ok(suggestions)
+    }
+}
+
+/// Creates a suggester with the bundled lists.
+impl Default for EmailSuggester {
+    fn default() -> EmailSuggester {
+        EmailSuggester::new()
+    }
+}
+
+/// Returns the number of insertions, deletions, substitutions and adjacent swaps that turn `a`
+/// into `b`, two ASCII strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    // distances[i][j] is the distance between the first i bytes of a and the first j bytes of b.
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    distances[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1).min(distances[i][j - 1] + 1).min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+/// Validates `email` and suggests corrections of its domain from the bundled lists; see
+/// [`EmailSuggester`].
+pub fn suggest_email(email: &str) -> Result<Vec<EmailSuggestion>, ValidationError> {
+    SUGGESTER.suggest(email)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contact::EmailError;
+    use crate::error::ErrorKind;
+
+    fn key(normalizer: &EmailNormalizer, email: &str) -> String {
+        normalizer.normalize(email).unwrap().canonical_key().to_string()
+    }
+
+    #[test]
+    fn test_normalizer_without_rules_only_lowercases_domain() {
+        let normalizer = EmailNormalizer::new();
+        assert_eq!(key(&normalizer, "John.Doe+news@GMAIL.com"), "John.Doe+news@gmail.com");
+        assert_eq!(key(&normalizer, "jo@Bücher.de"), "jo@xn--bcher-kva.de");
+    }
+
+    #[test]
+    fn test_known_providers_share_keys() {
+        let normalizer = EmailNormalizer::with_known_providers();
+        for email in ["johndoe@gmail.com", "John.Doe@gmail.com", "j.o.h.n.doe+news@googlemail.com", "JOHNDOE+a+b@Gmail.com"] {
+            assert_eq!(key(&normalizer, email), "johndoe@gmail.com", "{}", email);
+        }
+        assert_eq!(key(&normalizer, "jo.doe-shopping@yahoo.com"), "jo.doe@yahoo.com");
+        assert_eq!(key(&normalizer, "Jo+x@me.com"), "jo@icloud.com");
+        assert_eq!(key(&normalizer, "Jo.Doe+x@example.com"), "Jo.Doe+x@example.com");
+    }
+
+    #[test]
+    fn test_normalized_email_fields() {
+        let email = EmailNormalizer::with_known_providers().normalize("John.Doe+news@GoogleMail.com").unwrap();
+        assert_eq!(email.original(), "John.Doe+news@GoogleMail.com");
+        assert_eq!(email.prefix(), "John.Doe+news");
+        assert_eq!(email.domain(), "googlemail.com");
+        assert_eq!(email.subaddress(), Some("news"));
+        assert_eq!(email.canonical_key(), "johndoe@gmail.com");
+    }
+
+    #[test]
+    fn test_custom_rule_takes_precedence() {
+        let normalizer = EmailNormalizer::with_known_providers()
+            .with_rule(ProviderRule::new(["Example.com"]).subaddress_separator('+').ignore_dots())
+            .with_rule(ProviderRule::new(["gmail.com"]));
+        assert_eq!(key(&normalizer, "Jo.Doe+x@example.COM"), "JoDoe@example.com");
+        assert_eq!(key(&normalizer, "Jo.Doe+x@gmail.com"), "Jo.Doe+x@gmail.com");
+        assert_eq!(normalizer.rules()[0].domains(), ["gmail.com"]);
+    }
+
+    #[test]
+    fn test_normalizer_rejects_invalid_email() {
+        let err = EmailNormalizer::with_known_providers().normalize("jo..doe@gmail.com").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Email(EmailError::MisplacedDot));
+        let normalizer = EmailNormalizer::new().with_level(EmailLevel::Html5);
+        assert_eq!(key(&normalizer, "jo..doe@Gmail.com"), "jo..doe@gmail.com");
+    }
+
+    #[test]
+    fn test_classify_email() {
+        let classification = classify_email("jo@mailinator.com").unwrap();
+        assert!(classification.is_disposable() && !classification.is_role_account() && !classification.is_free_mail());
+        let classification = classify_email("Admin+alerts@Gmail.com").unwrap();
+        assert!(!classification.is_disposable() && classification.is_role_account() && classification.is_free_mail());
+        let classification = classify_email("jo.doe@example.com").unwrap();
+        assert!(!classification.is_disposable() && !classification.is_role_account() && !classification.is_free_mail());
+        assert_eq!(classification.email().to_unicode(), "jo.doe@example.com");
+    }
+
+    #[test]
+    fn test_classify_email_matches_subdomains_only() {
+        assert!(classify_email("jo@eu.mailinator.com").unwrap().is_disposable());
+        assert!(!classify_email("jo@notmailinator.com").unwrap().is_disposable());
+        assert!(classify_email("jo@com").is_err());
+    }
+
+    #[test]
+    fn test_classifier_with_updated_lists() {
+        let list = "# local additions\n\nthrowaway.example\n  Wegwerf.Example  \n";
+        let classifier = EmailClassifier::empty().with_disposable_domains(parse_list(list)).with_role_accounts(["kontakt"]);
+        assert!(classifier.classify("jo@throwaway.example").unwrap().is_disposable());
+        assert!(classifier.classify("jo@wegwerf.example").unwrap().is_disposable());
+        assert!(classifier.classify("Kontakt@bücher.de").unwrap().is_role_account());
+        assert!(!classifier.classify("jo@mailinator.com").unwrap().is_disposable());
+    }
+
+    #[test]
+    fn test_bundled_lists_are_well_formed() {
+        for list in [DISPOSABLE_DOMAINS, FREE_MAIL_DOMAINS, COMMON_DOMAINS] {
+            for domain in parse_list(list) {
+                assert_eq!(crate::idn::domain_to_ascii(domain).as_deref(), Some(domain), "{}", domain);
+            }
+        }
+        assert!(parse_list(ROLE_ACCOUNTS).all(|prefix| prefix == prefix.to_lowercase() && !prefix.contains('+')));
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("gmail.com", "gmail.com"), 0);
+        assert_eq!(edit_distance("gmial.com", "gmail.com"), 1);
+        assert_eq!(edit_distance("gmai.com", "gmail.com"), 1);
+        assert_eq!(edit_distance("hotmial.cm", "hotmail.com"), 2);
+        assert_eq!(edit_distance("", "com"), 3);
+    }
+
+    fn suggested(email: &str) -> Vec<String> {
+        suggest_email(email).unwrap().iter().map(|suggestion| suggestion.address().to_string()).collect()
+    }
+
+    #[test]
+    fn test_suggest_email() {
+        assert_eq!(suggested("Jo.Doe@gmial.com")[0], "Jo.Doe@gmail.com");
+        assert_eq!(suggested("jo@yaho.com")[0], "jo@yahoo.com");
+        assert_eq!(suggested("jo@example.con"), ["jo@example.com", "jo@example.co"]);
+        assert!(suggested("jo@gmail.com").is_empty());
+        assert!(suggested("jo@example.com").is_empty());
+        assert!(suggest_email("jo@gmial").is_err());
+    }
+
+    #[test]
+    fn test_suggestions_rank_common_domains_first() {
+        let suggestions = suggest_email("jo@hotmial.con").unwrap();
+        assert_eq!(suggestions[0].domain(), "hotmail.com");
+        assert_eq!(suggestions[0].distance(), 2);
+        assert_eq!(suggestions.last().unwrap().domain(), "hotmial.co");
+        assert_eq!(suggested("jo@gmai.com"), ["jo@gmail.com", "jo@mac.com", "jo@ymail.com", "jo@gmx.com", "jo@mail.com"]);
+    }
+
+    #[test]
+    fn test_suggester_with_custom_lists() {
+        let suggester = EmailSuggester::new().with_domains(["Example.org"]).with_tlds(["org"]).with_max_distance(1);
+        let suggestions = suggester.suggest("jo@exmaple.org").unwrap();
+        assert_eq!(suggestions.iter().map(EmailSuggestion::address).collect::<Vec<_>>(), ["jo@example.org"]);
+        assert!(suggester.suggest("jo@gmial.com").unwrap().iter().all(|suggestion| suggestion.domain() != "gmail.com"));
+    }
+}
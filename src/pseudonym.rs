@@ -0,0 +1,304 @@
+//! Reversible pseudonymization: replaces real SSNs and US phone numbers with fake but valid ones,
+//! deterministically from a secret key.
+//!
+//! Each value is mapped to its index among all values of the same shape (e.g. every SSN with a
+//! valid area, group and serial), the index is encrypted with a format-preserving Feistel
+//! cipher keyed by HMAC-SHA256, and the result is mapped back to a value. Results that fall
+//! outside the domain, or that the validator rejects, are encrypted again ("cycle walking"), so
+//! every pseudonym passes [`validate_ssn`] or [`validate_phone_number`], and [`Pseudonymizer::reveal_ssn`]
+//! and [`Pseudonymizer::reveal_phone`] recover the original with the same key.
+//!
+//! ```
+//! use regular_expressions::pseudonym::Pseudonymizer;
+//! use regular_expressions::validate_ssn;
+//!
+//! let pseudonymizer = Pseudonymizer::new("secret key");
+//! let fake = pseudonymizer.pseudonymize_ssn("123-45-6788").unwrap();
+//! assert!(validate_ssn(&fake.to_string()).is_ok());
+//! assert_eq!(pseudonymizer.reveal_ssn(&fake.to_string()).unwrap().to_string(), "123-45-6788");
+//! ```
+//!
+//! This is intended for producing realistic test data. The cipher follows the FE1 construction
+//! and is not a vetted implementation of a standard such as NIST FF1.
+//!
+//! Pseudonyms depend on which values the validators accept, since the walk skips the rest.
+
+use hmac::{Hmac, Mac};
+use phonenumber::PhoneNumber;
+use sha2::Sha256;
+
+use crate::error::ValidationError;
+use crate::identity::Ssn;
+use crate::{validate_phone_number, validate_ssn};
+
+/// Number of Feistel rounds.
+const ROUNDS: u8 = 8;
+
+/// Number of SSNs with a valid area (001-899 except 666), group (01-99) and serial (0001-9999).
+const SSN_DOMAIN: u64 = 898 * 99 * 9999;
+
+/// Number of NANP numbers with an area code and exchange of 200-999 and any line number.
+const PHONE_DOMAIN: u64 = 800 * 800 * 10_000;
+
+/// Replaces SSNs and phone numbers with valid pseudonyms derived from a secret key.
+#[derive(Clone)]
+pub struct Pseudonymizer {
+    mac: Hmac<Sha256>,
+}
+
+impl Pseudonymizer {
+    /// Creates a pseudonymizer keyed by `key`. The same key always maps a value to the same
+    /// pseudonym, and is needed to reveal the original.
+    pub fn new(key: impl AsRef<[u8]>) -> Pseudonymizer {
+        Pseudonymizer { mac: Hmac::new_from_slice(key.as_ref()).expect("HMAC accepts keys of any length") }
+    }
+
+    /// Returns the pseudonym of the SSN `ssn`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Ssn, ValidationError>` - Returns a valid SSN that differs from `ssn` (except by
+    ///   rare coincidence), or the broken rule if `ssn` is not itself valid.
+    pub fn pseudonymize_ssn(&self, ssn: &str) -> Result<Ssn, ValidationError> {
+        let index = ssn_index(&validate_ssn(ssn)?);
+        Ok(ssn_at(self.walk(Domain::Ssn, index, Direction::Encrypt)).expect("cycle walking ends on a valid SSN"))
+    }
+
+    /// Returns the original SSN of the pseudonym `pseudonym`.
+    pub fn reveal_ssn(&self, pseudonym: &str) -> Result<Ssn, ValidationError> {
+        let index = ssn_index(&validate_ssn(pseudonym)?);
+        Ok(ssn_at(self.walk(Domain::Ssn, index, Direction::Decrypt)).expect("cycle walking ends on a valid SSN"))
+    }
+
+    /// Returns the pseudonym of the US phone number `phone`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<PhoneNumber, ValidationError>` - Returns a valid number that differs from `phone`
+    ///   (except by rare coincidence), or the broken rule if `phone` is not itself valid. Any
+    ///   extension is kept unchanged.
+    pub fn pseudonymize_phone(&self, phone: &str) -> Result<PhoneNumber, ValidationError> {
+        let number = validate_phone_number(phone)?;
+        let index = phone_index(&number);
+        Ok(phone_at(self.walk(Domain::Phone, index, Direction::Encrypt), &number))
+    }
+
+    /// Returns the original US phone number of the pseudonym `pseudonym`.
+    pub fn reveal_phone(&self, pseudonym: &str) -> Result<PhoneNumber, ValidationError> {
+        let number = validate_phone_number(pseudonym)?;
+        let index = phone_index(&number);
+        Ok(phone_at(self.walk(Domain::Phone, index, Direction::Decrypt), &number))
+    }
+
+    /// Encrypts or decrypts `index` until it lands on a valid value of `domain`.
+    fn walk(&self, domain: Domain, index: u64, direction: Direction) -> u64 {
+        let mut x = index;
+        loop {
+            x = match direction {
+                Direction::Encrypt => self.encrypt(domain, x),
+                Direction::Decrypt => self.decrypt(domain, x),
+            };
+            if x < domain.size() && domain.is_valid(x) {
+                return x;
+            }
+        }
+    }
+
+    /// Encrypts `x` in `0..a*b` with the FE1 Feistel construction.
+    fn encrypt(&self, domain: Domain, mut x: u64) -> u64 {
+        let (a, b) = domain.moduli();
+        for round in 0..ROUNDS {
+            let (left, right) = (x / b, x % b);
+            let w = (left + self.round_function(domain, round, right, a)) % a;
+            x = a * right + w;
+        }
+        x
+    }
+
+    /// Inverts [`Pseudonymizer::encrypt`].
+    fn decrypt(&self, domain: Domain, mut x: u64) -> u64 {
+        let (a, b) = domain.moduli();
+        for round in (0..ROUNDS).rev() {
+            let (right, w) = (x / a, x % a);
+            let left = (w + a - self.round_function(domain, round, right, a)) % a;
+            x = b * left + right;
+        }
+        x
+    }
+
+    /// Returns the keyed hash of `(domain, round, right)`, reduced modulo `modulus`.
+    fn round_function(&self, domain: Domain, round: u8, right: u64, modulus: u64) -> u64 {
+        let mut mac = self.mac.clone();
+        mac.update(domain.label());
+        mac.update(&[round]);
+        mac.update(&right.to_be_bytes());
+        let digest = mac.finalize().into_bytes();
+        u64::from_be_bytes(digest[..8].try_into().unwrap()) % modulus
+    }
+}
+
+impl std::fmt::Debug for Pseudonymizer {
+    /// Leaves out the key.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pseudonymizer").finish_non_exhaustive()
+    }
+}
+
+/// The set of values a pseudonym is drawn from.
+#[derive(Debug, Clone, Copy)]
+enum Domain {
+    Ssn,
+    Phone,
+}
+
+impl Domain {
+    /// Returns the number of values in the domain.
+    fn size(&self) -> u64 {
+        match self {
+            Domain::Ssn => SSN_DOMAIN,
+            Domain::Phone => PHONE_DOMAIN,
+        }
+    }
+
+    /// Returns the moduli `a` and `b` of the Feistel cipher, with `a * b` at least the domain size.
+    fn moduli(&self) -> (u64, u64) {
+        let side = self.size().isqrt() + 1;
+        (side, side)
+    }
+
+    /// Separates the round functions of the domains, so one key gives unrelated permutations.
+    fn label(&self) -> &'static [u8] {
+        match self {
+            Domain::Ssn => b"ssn",
+            Domain::Phone => b"phone",
+        }
+    }
+
+    /// Returns whether the value at `index` passes the validator of the domain.
+    fn is_valid(&self, index: u64) -> bool {
+        match self {
+            Domain::Ssn => ssn_at(index).is_some(),
+            Domain::Phone => validate_phone_number(&phone_digits_at(index)).is_ok(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Encrypt,
+    Decrypt,
+}
+
+/// Returns the index of `ssn` in `0..SSN_DOMAIN`.
+fn ssn_index(ssn: &Ssn) -> u64 {
+    // Area numbers run 001-665 then 667-899.
+    let area = u64::from(ssn.area()) - if ssn.area() < 666 { 1 } else { 2 };
+    (area * 99 + u64::from(ssn.group()) - 1) * 9999 + u64::from(ssn.serial()) - 1
+}
+
+/// Returns the SSN at `index` in `0..SSN_DOMAIN`, or `None` for the few sample numbers in the
+/// domain, which are not valid.
+fn ssn_at(index: u64) -> Option<Ssn> {
+    let serial = index % 9999 + 1;
+    let group = index / 9999 % 99 + 1;
+    let area = index / 9999 / 99;
+    let area = area + if area < 665 { 1 } else { 2 };
+    Ssn::new(area as u16, group as u8, serial as u16)
+}
+
+/// Returns the index of the ten digit US number `number` in `0..PHONE_DOMAIN`.
+fn phone_index(number: &PhoneNumber) -> u64 {
+    let national = number.national().value();
+    let (area, exchange, line) = (national / 10_000_000, national / 10_000 % 1000, national % 10_000);
+    ((area - 200) * 800 + exchange - 200) * 10_000 + line
+}
+
+/// Returns the ten digits of the number at `index` in `0..PHONE_DOMAIN`.
+fn phone_digits_at(index: u64) -> String {
+    let (line, exchange, area) = (index % 10_000, index / 10_000 % 800 + 200, index / 10_000 / 800 + 200);
+    format!("{}{}{:04}", area, exchange, line)
+}
+
+/// Returns the number at `index`, with the extension of `original`.
+fn phone_at(index: u64, original: &PhoneNumber) -> PhoneNumber {
+    let mut number = phone_digits_at(index);
+    if let Some(extension) = original.extension() {
+        number.push_str(" x");
+        number.push_str(extension.as_ref());
+    }
+    validate_phone_number(&number).expect("the walk only stops on valid numbers")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contact::{format_phone_number, PhoneFormat};
+
+    fn national(number: &PhoneNumber) -> String {
+        format_phone_number(number, PhoneFormat::National)
+    }
+
+    #[test]
+    fn test_ssn_index_round_trips() {
+        for ssn in ["001-01-0001", "665-99-9999", "667-01-0001", "899-99-9999", "123-45-6788"] {
+            let ssn = validate_ssn(ssn).unwrap();
+            assert_eq!(ssn_at(ssn_index(&ssn)), Some(ssn));
+        }
+        assert_eq!(ssn_index(&validate_ssn("899-99-9999").unwrap()), SSN_DOMAIN - 1);
+    }
+
+    #[test]
+    fn test_feistel_round_trips() {
+        let pseudonymizer = Pseudonymizer::new("key");
+        for x in [0, 1, 12_345, SSN_DOMAIN - 1] {
+            assert_eq!(pseudonymizer.decrypt(Domain::Ssn, pseudonymizer.encrypt(Domain::Ssn, x)), x);
+        }
+    }
+
+    #[test]
+    fn test_pseudonymize_ssn_is_valid_and_reversible() {
+        let pseudonymizer = Pseudonymizer::new("key");
+        for ssn in ["123-45-6788", "001-01-0001", "899-99-9999", "667 12 3456"] {
+            let fake = pseudonymizer.pseudonymize_ssn(ssn).unwrap();
+            assert!(validate_ssn(&fake.to_string()).is_ok(), "{} is not valid", fake);
+            assert_ne!(fake, validate_ssn(ssn).unwrap());
+            assert_eq!(pseudonymizer.reveal_ssn(&fake.to_string()).unwrap(), validate_ssn(ssn).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_pseudonymize_ssn_is_deterministic_per_key() {
+        let fake = |key: &str| Pseudonymizer::new(key).pseudonymize_ssn("123-45-6788").unwrap();
+        assert_eq!(fake("one"), fake("one"));
+        assert_ne!(fake("one"), fake("two"));
+    }
+
+    #[test]
+    fn test_pseudonyms_are_stable() {
+        let pseudonymizer = Pseudonymizer::new("pinned key");
+        let fakes: Vec<String> =
+            ["123-45-6788", "001-01-0001", "899-99-9999"].iter().map(|ssn| pseudonymizer.pseudonymize_ssn(ssn).unwrap().to_string()).collect();
+        assert_eq!(fakes, ["560-66-7633", "144-76-0615", "632-91-5109"]);
+        let fakes: Vec<String> =
+            ["206-301-1473", "(212) 555-0100"].iter().map(|phone| national(&pseudonymizer.pseudonymize_phone(phone).unwrap())).collect();
+        assert_eq!(fakes, ["(470) 523-1592", "(820) 765-7718"]);
+    }
+
+    #[test]
+    fn test_pseudonymize_ssn_rejects_invalid_input() {
+        assert!(Pseudonymizer::new("key").pseudonymize_ssn("666-12-3456").is_err());
+    }
+
+    #[test]
+    fn test_pseudonymize_phone_is_valid_and_reversible() {
+        let pseudonymizer = Pseudonymizer::new("key");
+        for phone in ["(206) 301-1473", "509-331-1383", "206-301-0123 x45"] {
+            let fake = pseudonymizer.pseudonymize_phone(phone).unwrap();
+            let formatted = national(&fake);
+            assert!(validate_phone_number(&formatted).is_ok(), "{} is not valid", formatted);
+            assert_eq!(fake.extension(), validate_phone_number(phone).unwrap().extension());
+            let original = pseudonymizer.reveal_phone(&formatted).unwrap();
+            assert_eq!(national(&original), national(&validate_phone_number(phone).unwrap()));
+        }
+    }
+}
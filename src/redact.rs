@@ -0,0 +1,217 @@
+//! Redaction: rewrites free text with every SSN, phone number, email address and other field
+//! found by the [`scan`] module replaced by a mask.
+//!
+//! ```
+//! use regular_expressions::redact::{Mask, Redactor};
+//!
+//! let redactor = Redactor::new(Mask::Partial);
+//! assert_eq!(
+//!     redactor.redact("SSN 123-45-6788, call (206) 301-0100 or mail jo@example.com"),
+//!     "SSN ***-**-6788, call (***) ***-0100 or mail j***@example.com"
+//! );
+//! ```
+
+use std::collections::HashMap;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::scan::{self, FieldKind};
+
+/// How a redacted value is replaced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Mask {
+    /// Keeps the part of the value needed to tell values apart: `***-**-6789`, `(***) ***-0100`,
+    /// `j***@example.com`.
+    Partial,
+    /// Replaces the value with a keyed hash token, e.g. `<SSN:4f1c2a9be03d>`. Equal values get
+    /// equal tokens, so redacted logs can still be correlated.
+    Hash,
+    /// Replaces the value with its kind, e.g. `<SSN>`.
+    Placeholder,
+}
+
+/// Number of hex digits of the HMAC kept in a hash token.
+const HASH_TOKEN_LENGTH: usize = 12;
+
+/// Rewrites text with detected fields masked.
+///
+/// # Description
+///
+/// * Every [`FieldKind`] is redacted with the mask given to [`Redactor::new`] unless
+///   [`Redactor::with_mask`] sets another mask for it, or [`Redactor::without`] leaves it as is.
+/// * Hash tokens are an HMAC-SHA256 of the value under the key set with
+///   [`Redactor::with_hash_key`]. Without a key, tokens of SSNs and phone numbers can be reversed
+///   by hashing every possible number, so set a secret key before shipping hashed text to third
+///   parties.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    masks: HashMap<FieldKind, Mask>,
+    hash_key: Vec<u8>,
+}
+
+impl Redactor {
+    /// Creates a redactor that masks every field kind with `mask`.
+    pub fn new(mask: Mask) -> Redactor {
+        Redactor { masks: FieldKind::ALL.into_iter().map(|kind| (kind, mask)).collect(), hash_key: Vec::new() }
+    }
+
+    /// Masks fields of `kind` with `mask` instead.
+    pub fn with_mask(mut self, kind: FieldKind, mask: Mask) -> Redactor {
+        self.masks.insert(kind, mask);
+        self
+    }
+
+    /// Leaves fields of `kind` unredacted.
+    pub fn without(mut self, kind: FieldKind) -> Redactor {
+        self.masks.remove(&kind);
+        self
+    }
+
+    /// Sets the secret key of the HMAC used by [`Mask::Hash`].
+    pub fn with_hash_key(mut self, key: impl Into<Vec<u8>>) -> Redactor {
+        self.hash_key = key.into();
+        self
+    }
+
+    /// Returns `text` with every field of a redacted kind replaced by its mask.
+    pub fn redact(&self, text: &str) -> String {
+        let kinds: Vec<FieldKind> = self.masks.keys().copied().collect();
+        let mut redacted = String::with_capacity(text.len());
+        let mut end = 0;
+        for finding in scan::scan_for(text, &kinds) {
+            let span = finding.span();
+            redacted.push_str(&text[end..span.start]);
+            redacted.push_str(&self.mask(finding.kind(), finding.as_str()));
+            end = span.end;
+        }
+        redacted.push_str(&text[end..]);
+        redacted
+    }
+
+    /// Returns the mask of `value`, a field of `kind`.
+    fn mask(&self, kind: FieldKind, value: &str) -> String {
+        match self.masks[&kind] {
+            Mask::Partial => partial_mask(kind, value),
+            Mask::Hash => format!("<{}:{}>", kind.name().to_ascii_uppercase(), self.hash_token(kind, value)),
+            Mask::Placeholder => format!("<{}>", kind.name().to_ascii_uppercase()),
+        }
+    }
+
+    /// Returns the leading hex digits of the HMAC of the canonical form of `value`, so that
+    /// `123 45 6788` and `123-45-6788` get the same token.
+    fn hash_token(&self, kind: FieldKind, value: &str) -> String {
+        let canonical = match kind {
+            FieldKind::Ssn | FieldKind::Phone | FieldKind::Zip => digits(value),
+            FieldKind::Email | FieldKind::Url => value.to_ascii_lowercase(),
+            _ => value.to_string(),
+        };
+        let mut mac = Hmac::<Sha256>::new_from_slice(&self.hash_key).expect("HMAC accepts keys of any length");
+        mac.update(kind.name().as_bytes());
+        mac.update(b":");
+        mac.update(canonical.as_bytes());
+        let hex: String = mac.finalize().into_bytes().iter().map(|byte| format!("{:02x}", byte)).collect();
+        hex[..HASH_TOKEN_LENGTH].to_string()
+    }
+}
+
+/// Redacts every field found in `text` with `mask`; see [`Redactor`].
+pub fn redact(text: &str, mask: Mask) -> String {
+    Redactor::new(mask).redact(text)
+}
+
+/// Returns the partial mask of `value`, a field of `kind`.
+fn partial_mask(kind: FieldKind, value: &str) -> String {
+    let digits = digits(value);
+    match kind {
+        FieldKind::Ssn => format!("***-**-{}", &digits[digits.len() - 4..]),
+        FieldKind::Phone => format!("(***) ***-{}", &digits[digits.len() - 4..]),
+        FieldKind::Email => {
+            let (prefix, domain) = value.split_once('@').unwrap_or((value, ""));
+            let first = prefix.chars().next().map_or(String::new(), String::from);
+            format!("{}***@{}", first, domain)
+        }
+        FieldKind::Address => {
+            // Keep the street, hide the house number.
+            let street = value.trim_start_matches(|ch: char| ch.is_ascii_digit());
+            format!("****{}", street)
+        }
+        FieldKind::Url => {
+            // Keep the scheme and host, hide the path and query.
+            let host_start = value.find("://").map_or(0, |i| i + 3);
+            match value[host_start..].find('/') {
+                Some(path) => format!("{}/***", &value[..host_start + path]),
+                None => value.to_string(),
+            }
+        }
+        FieldKind::Date => format!("**/**/{}", &digits[digits.len() - 4..]),
+        FieldKind::Currency => "$***".to_string(),
+        FieldKind::Zip => format!("{}**", &digits[..3]),
+    }
+}
+
+/// Returns the ASCII digits of `value`.
+fn digits(value: &str) -> String {
+    value.chars().filter(char::is_ascii_digit).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "jo@example.com (SSN 123 45 6788) lives at 3456 Cherry Tree Boulevard, Seattle, WA 98101; \
+                        call 206-301-0100, paid $1,234.56 on 02/29/2020, see https://example.com/orders?id=7";
+
+    #[test]
+    fn test_redact_partial() {
+        assert_eq!(
+            redact(TEXT, Mask::Partial),
+            "j***@example.com (SSN ***-**-6788) lives at **** Cherry Tree Boulevard, Seattle, WA 981**; \
+             call (***) ***-0100, paid $*** on **/**/2020, see https://example.com/***"
+        );
+    }
+
+    #[test]
+    fn test_redact_placeholder() {
+        assert_eq!(
+            redact(TEXT, Mask::Placeholder),
+            "<EMAIL> (SSN <SSN>) lives at <ADDRESS>, Seattle, WA <ZIP>; call <PHONE>, paid <CURRENCY> on <DATE>, see <URL>"
+        );
+    }
+
+    #[test]
+    fn test_redact_hash_tokens_match_equal_values() {
+        let redactor = Redactor::new(Mask::Hash).with_hash_key("secret");
+        let redacted = redactor.redact("123-45-6788 and 123 45 6788 but not 123-45-6787");
+        let tokens: Vec<&str> = redacted.split(' ').filter(|word| word.starts_with("<SSN:")).collect();
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0], tokens[1]);
+        assert_ne!(tokens[0], tokens[2]);
+        assert_eq!(tokens[0].len(), "<SSN:>".len() + HASH_TOKEN_LENGTH);
+    }
+
+    #[test]
+    fn test_redact_hash_tokens_depend_on_key() {
+        let token = |key: &str| Redactor::new(Mask::Hash).with_hash_key(key).redact("123-45-6788");
+        assert_ne!(token("one"), token("two"));
+    }
+
+    #[test]
+    fn test_redactor_per_kind_masks() {
+        let redactor = Redactor::new(Mask::Placeholder).with_mask(FieldKind::Ssn, Mask::Partial).without(FieldKind::Email);
+        assert_eq!(redactor.redact("jo@example.com 123-45-6788 206-301-0100"), "jo@example.com ***-**-6788 <PHONE>");
+    }
+
+    #[test]
+    fn test_redact_leaves_text_without_fields_unchanged() {
+        assert_eq!(redact("nothing to see here", Mask::Partial), "nothing to see here");
+    }
+
+    #[test]
+    fn test_redact_leaves_non_ascii_digits_unchanged() {
+        let text = "SSN ١٢٣-٤٥-٦٧٨٨, call (２０６) ３０１-０１００ on ０３/０５/２０２３";
+        assert_eq!(redact(text, Mask::Placeholder), text);
+        assert_eq!(redact("١٢٣-٤٥-٦٧٨٨ or 123-45-6788", Mask::Partial), "١٢٣-٤٥-٦٧٨٨ or ***-**-6788");
+    }
+}
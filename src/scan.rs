@@ -0,0 +1,313 @@
+//! Free-text scanning: finds SSNs, phone numbers, emails, house addresses, URLs, dates, currency
+//! amounts and ZIP codes anywhere in a document.
+//!
+//! The validators in the other modules are anchored and check a whole input. The scanner runs an
+//! unanchored pattern per field kind over the document, confirms each candidate with the
+//! corresponding validator (so `666-12-3456` is not reported as an SSN), and resolves overlapping
+//! candidates in favour of the leftmost, then longest, match.
+//!
+//! ```
+//! use regular_expressions::scan::{self, FieldKind};
+//!
+//! let findings = scan::scan("Call (206) 301-1473 or mail jo@example.com about SSN 123-45-6788.");
+//! let kinds: Vec<FieldKind> = findings.iter().map(|finding| finding.kind()).collect();
+//! assert_eq!(kinds, vec![FieldKind::Phone, FieldKind::Email, FieldKind::Ssn]);
+//! assert_eq!(findings[2].as_str(), "123-45-6788");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::Span;
+use crate::location::STREET_TYPES;
+use crate::{
+    validate_address, validate_currency, validate_date, validate_email, validate_phone_number, validate_ssn, validate_url,
+};
+
+/// Finds SSN candidates: nine digits, optionally split 3-2-4 by dashes or spaces.
+static SSN_CANDIDATE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b[0-9]{3}[-\s]?[0-9]{2}[-\s]?[0-9]{4}\b").unwrap());
+
+/// Finds US phone number candidates: ten digits with an optional +1, split 3-3-4.
+static PHONE_CANDIDATE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:\+?\b1[-\s]?)?(?:\([0-9]{3}\)|\b[0-9]{3})[-\s]?[0-9]{3}[-\s]?[0-9]{4}\b").unwrap());
+
+/// Finds email candidates: a run of prefix characters, an @ and a run of domain characters.
+static EMAIL_CANDIDATE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[a-zA-Z0-9!#$%&'*+/=?^_`{|}~.-]+@[a-zA-Z0-9.-]+").unwrap());
+
+/// Finds house address candidates: a house number, up to four words of street name and a street type.
+static ADDRESS_CANDIDATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)\b[0-9]+(?:[ \t]+[a-z]+){{1,4}}?[ \t]+(?:{})\b", STREET_TYPES.join("|"))).unwrap()
+});
+
+/// Finds URL candidates that start with a scheme or `www.`, so file names such as `notes.txt` are
+/// not reported.
+static URL_CANDIDATE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?:https?://|www\.)[a-z0-9.-]+(?:/[-a-z0-9()@:%_+.~#?&=]*)?").unwrap());
+
+/// Finds date candidates in MM-DD-YYYY or MM/DD/YYYY format.
+static DATE_CANDIDATE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b[0-9]{2}[-/][0-9]{2}[-/][0-9]{4}\b").unwrap());
+
+/// Finds currency candidates: a dollar sign followed by digits, commas and optional cents.
+static CURRENCY_CANDIDATE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\$[0-9][0-9,]*(?:\.[0-9]+)?").unwrap());
+
+/// Finds ZIP codes: five digits with an optional four digit extension.
+static ZIP_CANDIDATE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b[0-9]{5}(?:-[0-9]{4})?\b").unwrap());
+
+/// The kinds of field the scanner looks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum FieldKind {
+    Ssn,
+    Phone,
+    Email,
+    Address,
+    Url,
+    Date,
+    Currency,
+    Zip,
+}
+
+impl FieldKind {
+    /// Every field kind, in the order overlapping matches of the same length are preferred.
+    pub const ALL: [FieldKind; 8] = [
+        FieldKind::Ssn,
+        FieldKind::Phone,
+        FieldKind::Email,
+        FieldKind::Address,
+        FieldKind::Url,
+        FieldKind::Date,
+        FieldKind::Currency,
+        FieldKind::Zip,
+    ];
+
+    /// Returns the short, stable name of the field kind, e.g. `"ssn"` or `"zip"`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FieldKind::Ssn => "ssn",
+            FieldKind::Phone => "phone",
+            FieldKind::Email => "email",
+            FieldKind::Address => "address",
+            FieldKind::Url => "url",
+            FieldKind::Date => "date",
+            FieldKind::Currency => "currency",
+            FieldKind::Zip => "zip",
+        }
+    }
+
+    /// Returns the unanchored pattern that finds candidates of this kind.
+    fn candidate_regex(&self) -> &'static Regex {
+        match self {
+            FieldKind::Ssn => &SSN_CANDIDATE_REGEX,
+            FieldKind::Phone => &PHONE_CANDIDATE_REGEX,
+            FieldKind::Email => &EMAIL_CANDIDATE_REGEX,
+            FieldKind::Address => &ADDRESS_CANDIDATE_REGEX,
+            FieldKind::Url => &URL_CANDIDATE_REGEX,
+            FieldKind::Date => &DATE_CANDIDATE_REGEX,
+            FieldKind::Currency => &CURRENCY_CANDIDATE_REGEX,
+            FieldKind::Zip => &ZIP_CANDIDATE_REGEX,
+        }
+    }
+
+    /// Returns the characters trimmed from the end of a candidate, such as the full stop that
+    /// ends a sentence after an email address.
+    fn trailing_punctuation(&self) -> &'static [char] {
+        match self {
+            FieldKind::Email => &['.', '-'],
+            FieldKind::Url => &['.', ',', ')', '?', ':'],
+            FieldKind::Currency => &[','],
+            _ => &[],
+        }
+    }
+
+    /// Checks a candidate with the validator for this kind.
+    fn is_valid(&self, candidate: &str) -> bool {
+        match self {
+            FieldKind::Ssn => validate_ssn(candidate).is_ok(),
+            FieldKind::Phone => validate_phone_number(candidate).is_ok(),
+            FieldKind::Email => validate_email(candidate).is_ok(),
+            FieldKind::Address => validate_address(candidate).is_ok(),
+            FieldKind::Url => validate_url(candidate).is_ok(),
+            FieldKind::Date => validate_date(candidate).is_ok(),
+            FieldKind::Currency => validate_currency(candidate).is_ok(),
+            FieldKind::Zip => true,
+        }
+    }
+}
+
+impl fmt::Display for FieldKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for FieldKind {
+    type Err = String;
+
+    /// Parses a field kind from its [`FieldKind::name`].
+    fn from_str(name: &str) -> Result<FieldKind, String> {
+        FieldKind::ALL.into_iter().find(|kind| kind.name() == name).ok_or_else(|| {
+            let names: Vec<&str> = FieldKind::ALL.iter().map(FieldKind::name).collect();
+            format!("unknown field kind {:?}, expected one of: {}", name, names.join(", "))
+        })
+    }
+}
+
+/// A value of a known field kind found in a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Finding<'t> {
+    kind: FieldKind,
+    span: Span,
+    value: &'t str,
+}
+
+impl<'t> Finding<'t> {
+    /// Returns the kind of field found.
+    pub fn kind(&self) -> FieldKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the value in the document.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the value as it appears in the document.
+    pub fn as_str(&self) -> &'t str {
+        self.value
+    }
+}
+
+/// Finds every field of every kind in `text`.
+///
+/// # Returns
+///
+/// * `Vec<Finding>` - The findings in the order they appear in `text`. No two findings overlap.
+pub fn scan(text: &str) -> Vec<Finding<'_>> {
+    scan_for(text, &FieldKind::ALL)
+}
+
+/// Finds every field of the given `kinds` in `text`.
+///
+/// # Description
+///
+/// * Each candidate is confirmed with the validator of its kind. ZIP codes have no validator of
+///   their own, so any five digit number (with an optional `-1234` extension) is reported.
+/// * Phone numbers are found in their ten digit forms; vanity letters and extensions are not.
+/// * URLs are only found when they start with `http://`, `https://` or `www.`.
+/// * Where candidates overlap, the one that starts first wins, then the longest, then the kind
+///   listed first in [`FieldKind::ALL`]. For example `$12345` is a currency amount, not a ZIP code.
+///
+/// # Returns
+///
+/// * `Vec<Finding>` - The findings in the order they appear in `text`. No two findings overlap.
+pub fn scan_for<'t>(text: &'t str, kinds: &[FieldKind]) -> Vec<Finding<'t>> {
+    let mut candidates: Vec<Finding<'t>> = Vec::new();
+    for kind in FieldKind::ALL.into_iter().filter(|kind| kinds.contains(kind)) {
+        for mat in kind.candidate_regex().find_iter(text) {
+            let value = mat.as_str().trim_end_matches(kind.trailing_punctuation());
+            if !value.is_empty() && kind.is_valid(value) {
+                let span = Span::new(mat.start(), mat.start() + value.len());
+                candidates.push(Finding { kind, span, value });
+            }
+        }
+    }
+
+    // Leftmost first, then longest, then by kind; the sort is stable so kinds keep their order.
+    candidates.sort_by_key(|finding| (finding.span.start, std::cmp::Reverse(finding.span.end)));
+    let mut findings: Vec<Finding<'t>> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        if findings.last().is_none_or(|last| candidate.span.start >= last.span.end) {
+            findings.push(candidate);
+        }
+    }
+    findings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn found(text: &str) -> Vec<(FieldKind, &str)> {
+        scan(text).iter().map(|finding| (finding.kind(), finding.as_str())).collect()
+    }
+
+    #[test]
+    fn test_scan_finds_every_kind() {
+        let text = "SSN 123-45-6788, phone (206) 301-1473, mail jo@example.com, see https://example.com/a, \
+                    due 02/29/2020, paid $1,234.56, ZIP 98101-1234.";
+        assert_eq!(
+            found(text),
+            vec![
+                (FieldKind::Ssn, "123-45-6788"),
+                (FieldKind::Phone, "(206) 301-1473"),
+                (FieldKind::Email, "jo@example.com"),
+                (FieldKind::Url, "https://example.com/a"),
+                (FieldKind::Date, "02/29/2020"),
+                (FieldKind::Currency, "$1,234.56"),
+                (FieldKind::Zip, "98101-1234"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scan_reports_byte_offsets() {
+        let text = "ssn: 123 45 6788";
+        let findings = scan(text);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].span(), Span::new(5, 16));
+        assert_eq!(findings[0].span().slice(text), "123 45 6788");
+    }
+
+    #[test]
+    fn test_scan_skips_invalid_candidates() {
+        assert_eq!(found("not an SSN: 666-12-3456, not a date: 13/01/2020"), vec![]);
+    }
+
+    #[test]
+    fn test_scan_ignores_non_ascii_digits() {
+        let text = "ssn ١٢٣-٤٥-٦٧٨٨ on ０３/０５/２０２３, paid $１２, zip ٩٨١٠١, call (２０６) ３０１-１４７３";
+        assert_eq!(found(text), vec![]);
+        assert_eq!(found("ssn ١٢٣-٤٥-٦٧٨٨ or 123-45-6788"), vec![(FieldKind::Ssn, "123-45-6788")]);
+    }
+
+    #[test]
+    fn test_scan_trims_trailing_punctuation() {
+        assert_eq!(found("Write to jo@example.com."), vec![(FieldKind::Email, "jo@example.com")]);
+        assert_eq!(found("(see www.example.com)"), vec![(FieldKind::Url, "www.example.com")]);
+    }
+
+    #[test]
+    fn test_scan_prefers_longest_overlapping_match() {
+        assert_eq!(found("paid $12345"), vec![(FieldKind::Currency, "$12345")]);
+        assert_eq!(found("jo@www.example.com"), vec![(FieldKind::Email, "jo@www.example.com")]);
+        assert_eq!(found("call +1 206 301 1473"), vec![(FieldKind::Phone, "+1 206 301 1473")]);
+    }
+
+    #[test]
+    fn test_scan_finds_addresses() {
+        assert_eq!(
+            found("Ship to 3456 Cherry Tree Boulevard. Or 12 Elm St"),
+            vec![(FieldKind::Address, "3456 Cherry Tree Boulevard"), (FieldKind::Address, "12 Elm St")]
+        );
+        assert_eq!(found("I ate 12 apples on the way"), vec![]);
+    }
+
+    #[test]
+    fn test_scan_for_limits_kinds() {
+        let findings = scan_for("123-45-6788 at 98101", &[FieldKind::Zip]);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].as_str(), "98101");
+    }
+
+    #[test]
+    fn test_field_kind_names_round_trip() {
+        for kind in FieldKind::ALL {
+            assert_eq!(kind.name().parse::<FieldKind>(), Ok(kind));
+        }
+        assert!("zodiac".parse::<FieldKind>().is_err());
+    }
+}
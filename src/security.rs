@@ -0,0 +1,208 @@
+//! Credentials: password strength rules.
+
+use std::fmt;
+
+use std::sync::LazyLock;
+
+use regex::{Regex, RegexSet};
+
+use crate::error::{Span, ValidationError};
+use crate::validator::Validator;
+
+/// Minimum password length, in bytes.
+const MIN_PASSWORD_LENGTH: usize = 10;
+
+/// The character classes a password must contain, checked in a single pass. The index of each
+/// pattern lines up with the error reported when it is missing, see [`missing_class_error`].
+static REQUIRED_CLASSES: LazyLock<RegexSet> =
+    LazyLock::new(|| RegexSet::new([r"[A-Z]", r"[a-z]", r"[0-9]", r"[[:punct:]]"]).unwrap());
+
+/// Matches a run of more than three lowercase letters.
+static CONSECUTIVE_LOWERCASE_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"[a-z]{4,}").unwrap());
+
+/// The rule a password broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PasswordError {
+    /// The password is shorter than `min` bytes.
+    TooShort { len: usize, min: usize },
+    /// The password has no uppercase letter.
+    MissingUppercase,
+    /// The password has no lowercase letter.
+    MissingLowercase,
+    /// The password has no digit.
+    MissingDigit,
+    /// The password has no punctuation mark.
+    MissingPunctuation,
+    /// More than three lowercase letters appear in a row, starting at byte offset `at`.
+    TooManyConsecutiveLowercase { at: usize },
+}
+
+impl fmt::Display for PasswordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordError::TooShort { len, min } => write!(f, "password is {} characters long, at least {} are required", len, min),
+            PasswordError::MissingUppercase => write!(f, "password must contain an uppercase letter"),
+            PasswordError::MissingLowercase => write!(f, "password must contain a lowercase letter"),
+            PasswordError::MissingDigit => write!(f, "password must contain a digit"),
+            PasswordError::MissingPunctuation => write!(f, "password must contain a punctuation mark"),
+            PasswordError::TooManyConsecutiveLowercase { at } => {
+                write!(f, "password has more than 3 consecutive lowercase letters starting at position {}", at)
+            }
+        }
+    }
+}
+
+/// Validates a password string by checking if it meets the specified requirements.
+///
+/// # Description
+///
+/// * This function checks if the input password string meets the following requirements:
+///   - At least 10 characters in length
+///   - Contains at least one uppercase character
+///   - Contains at least one lowercase character
+///   - Contains at least one digit
+///   - Contains at least one punctuation mark
+///   - Does not have more than 3 consecutive lowercase characters
+///
+/// # Arguments
+///
+/// * `password: &str` - The input string containing the password to validate.
+///
+/// # Returns
+///
+/// * `Result<(), ValidationError>` - Returns `Ok(())` if the input password meets all the requirements, or the
+///   first requirement it breaks (a [`PasswordError`]) otherwise.
+pub fn validate_password(password: &str) -> Result<(), ValidationError> {
+    // Check each requirement in turn and report the first one that fails
+    let whole = Span::whole(password);
+    if password.len() < MIN_PASSWORD_LENGTH {
+        return Err(ValidationError::new(PasswordError::TooShort { len: password.len(), min: MIN_PASSWORD_LENGTH }, whole));
+    }
+    let classes = REQUIRED_CLASSES.matches(password);
+    if let Some(missing) = (0..REQUIRED_CLASSES.len()).find(|&i| !classes.matched(i)) {
+        return Err(ValidationError::new(missing_class_error(missing), whole));
+    }
+    if let Some(run) = CONSECUTIVE_LOWERCASE_REGEX.find(password) {
+        return Err(ValidationError::new(PasswordError::TooManyConsecutiveLowercase { at: run.start() }, run));
+    }
+
+    Ok(())
+}
+
+/// Returns the error for the pattern at `index` in [`REQUIRED_CLASSES`].
+fn missing_class_error(index: usize) -> PasswordError {
+    match index {
+        0 => PasswordError::MissingUppercase,
+        1 => PasswordError::MissingLowercase,
+        2 => PasswordError::MissingDigit,
+        _ => PasswordError::MissingPunctuation,
+    }
+}
+
+/// The [`Validator`] for passwords; see [`validate_password`].
+///
+/// Passwords are never rewritten, so [`Validator::normalize`] returns the input unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PasswordValidator;
+
+impl Validator for PasswordValidator {
+    fn name(&self) -> &'static str {
+        "password"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "At least 10 characters in length.",
+            "Contains at least one uppercase character.",
+            "Contains at least one lowercase character.",
+            "Contains at least one digit.",
+            "Contains at least one punctuation mark.",
+            "Does not have more than 3 consecutive lowercase characters.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_password(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_validate_password_valid_complex() {
+        assert!(validate_password("A1b@c$d2E#").is_ok());
+    }
+
+    #[test]
+    fn test_validate_password_valid_minimum_length() {
+        assert!(validate_password("A1b#c2D!c1").is_ok());
+    }
+
+    #[test]
+    fn test_validate_password_valid_no_consecutive_lowercase() {
+        assert!(validate_password("A1b#cD2e#F3").is_ok());
+    }
+
+    #[test]
+    fn test_validate_password_valid_all_requirements_met() {
+        assert!(validate_password("A1b@c#D2e!F3").is_ok());
+    }
+
+    #[test]
+    fn test_validate_password_valid_with_spaces() {
+        assert!(validate_password("A1b @c #D2").is_ok());
+    }
+
+    #[test]
+    fn test_validate_password_invalid_too_short() {
+        assert!(validate_password("A1b#c2").is_err());
+    }
+
+    #[test]
+    fn test_validate_password_invalid_missing_uppercase() {
+        assert!(validate_password("a1b#c2d$3").is_err());
+    }
+
+    #[test]
+    fn test_validate_password_invalid_missing_lowercase() {
+        assert!(validate_password("A1B@C#D2E$").is_err());
+    }
+
+    #[test]
+    fn test_validate_password_invalid_missing_digit() {
+        assert!(validate_password("AaBbCcDdEe").is_err());
+        assert!(validate_password("AaBbCc!Dd١").is_err());
+    }
+
+    #[test]
+    fn test_validate_password_invalid_consecutive_lowercase() {
+        assert!(validate_password("A1#@bcdefE").is_err());
+    }
+
+    #[test]
+    fn test_validate_password_invalid_missing_punctuation() {
+        assert!(validate_password("Ab1cAb1cAb").is_err());
+    }
+
+    #[test]
+    fn test_validate_password_reports_too_short() {
+        let err = validate_password("A1b#c2").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Password(PasswordError::TooShort { len: 6, min: 10 }));
+    }
+
+    #[test]
+    fn test_validate_password_reports_missing_punctuation() {
+        let err = validate_password("Ab1cAb1cAb").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Password(PasswordError::MissingPunctuation));
+    }
+
+    #[test]
+    fn test_validate_password_reports_consecutive_lowercase() {
+        let err = validate_password("A1#@bcdefE").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Password(PasswordError::TooManyConsecutiveLowercase { at: 4 }));
+        assert_eq!(err.span(), Span::new(4, 9));
+    }
+}
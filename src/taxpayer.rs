@@ -0,0 +1,328 @@
+//! US taxpayer identification numbers: SSNs, ITINs, ATINs and EINs.
+//!
+//! ```
+//! use regular_expressions::taxpayer::{validate_taxpayer_id, TaxpayerIdKind};
+//!
+//! let itin = validate_taxpayer_id("912 70 1234").unwrap();
+//! assert_eq!(itin.kind(), TaxpayerIdKind::Itin);
+//! assert_eq!(itin.to_string(), "912-70-1234");
+//! assert_eq!(validate_taxpayer_id("12-3456789").unwrap().kind(), TaxpayerIdKind::Ein);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::{Span, ValidationError};
+use crate::identity::validate_ssn;
+use crate::validator::Validator;
+
+/// Matches a nine-digit SSN, ITIN or ATIN as AAA-GG-SSSS, or an EIN as NN-NNNNNNN.
+static TAXPAYER_ID_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:(?P<area>[0-9]{3})[-\s]?(?P<group>[0-9]{2})[-\s]?(?P<serial>[0-9]{4})|(?P<prefix>[0-9]{2})[-\s](?P<number>[0-9]{7}))$").unwrap()
+});
+
+/// The EIN prefixes the IRS has never assigned to a campus or to online applications.
+const UNASSIGNED_EIN_PREFIXES: [u8; 17] = [0, 7, 8, 9, 17, 18, 19, 28, 29, 49, 69, 70, 78, 79, 89, 96, 97];
+
+/// The kind of a taxpayer identification number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaxpayerIdKind {
+    /// A Social Security Number, issued by the SSA to people.
+    Ssn,
+    /// An Individual Taxpayer Identification Number, issued by the IRS to people who cannot get an
+    /// SSN.
+    Itin,
+    /// An Adoption Taxpayer Identification Number, issued by the IRS for children being adopted.
+    Atin,
+    /// An Employer Identification Number, issued by the IRS to businesses and other entities.
+    Ein,
+}
+
+impl TaxpayerIdKind {
+    /// Returns the usual abbreviation of the kind, e.g. `ITIN`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TaxpayerIdKind::Ssn => "SSN",
+            TaxpayerIdKind::Itin => "ITIN",
+            TaxpayerIdKind::Atin => "ATIN",
+            TaxpayerIdKind::Ein => "EIN",
+        }
+    }
+}
+
+impl fmt::Display for TaxpayerIdKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The rule a taxpayer identification number broke.
+///
+/// Numbers of the SSN form with an area below 900 break the rules of SSNs instead, reported as
+/// an [`SsnError`](crate::identity::SsnError).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaxpayerIdError {
+    /// The input is neither nine digits split 3-2-4 nor split 2-7.
+    Malformed,
+    /// The number starts with 9, as ITINs and ATINs do, but its group number (middle two digits)
+    /// is not 50-65, 70-88, 90-92 or 94-99 for an ITIN, nor 93 for an ATIN.
+    InvalidItinGroup(u8),
+    /// The prefix (first two digits) of the EIN was never assigned by the IRS.
+    InvalidEinPrefix(u8),
+    /// The serial number (last four digits) of the ITIN or ATIN is 0000, which, as for SSNs, is
+    /// never issued.
+    ZeroSerial,
+}
+
+impl fmt::Display for TaxpayerIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaxpayerIdError::Malformed => {
+                write!(f, "taxpayer ID must be an SSN, ITIN or ATIN in the form AAA-GG-SSSS, or an EIN in the form NN-NNNNNNN")
+            }
+            TaxpayerIdError::InvalidItinGroup(group) => write!(f, "ITIN group number {:02} is never issued", group),
+            TaxpayerIdError::InvalidEinPrefix(prefix) => write!(f, "EIN prefix {:02} is never assigned", prefix),
+            TaxpayerIdError::ZeroSerial => write!(f, "ITIN and ATIN serial number must not be 0000"),
+        }
+    }
+}
+
+/// A valid US taxpayer identification number and its kind.
+///
+/// # Description
+///
+/// * Produced by [`validate_taxpayer_id`] or by parsing a string with [`str::parse`].
+/// * Displays in the canonical form of its kind: `AAA-GG-SSSS` for SSNs, ITINs and ATINs, and
+///   `NN-NNNNNNN` for EINs.
+/// * Orders by kind, then by number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TaxpayerId {
+    kind: TaxpayerIdKind,
+    number: u32,
+}
+
+impl TaxpayerId {
+    /// Returns the kind of the number.
+    pub fn kind(&self) -> TaxpayerIdKind {
+        self.kind
+    }
+
+    /// Returns the nine digits of the number, without separators.
+    pub fn digits(&self) -> String {
+        format!("{:09}", self.number)
+    }
+}
+
+impl fmt::Display for TaxpayerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.digits();
+        match self.kind {
+            TaxpayerIdKind::Ein => write!(f, "{}-{}", &digits[..2], &digits[2..]),
+            _ => write!(f, "{}-{}-{}", &digits[..3], &digits[3..5], &digits[5..]),
+        }
+    }
+}
+
+impl FromStr for TaxpayerId {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<TaxpayerId, ValidationError> {
+        validate_taxpayer_id(s)
+    }
+}
+
+/// Validates a US taxpayer identification number and tells its kind.
+///
+/// # Arguments
+///
+/// * `taxpayer_id` - A string slice that holds the SSN, ITIN, ATIN or EIN.
+///
+/// # Rules
+///
+/// * SSNs, ITINs and ATINs are nine digits, optionally separated as AAA-GG-SSSS by dashes or
+///   spaces. Nine digits without separators are read this way, never as an EIN.
+/// * Numbers with an area (first three digits) below 900 are SSNs and follow the rules of
+///   [`validate_ssn`].
+/// * Numbers with an area of 900-999 are ATINs if their group (middle two digits) is 93, and ITINs
+///   if it is 50-65, 70-88, 90-92 or 94-99. Their serial (last four digits) must not be 0000.
+/// * EINs are nine digits separated as NN-NNNNNNN by a dash or space, whose prefix (first two
+///   digits) is one the IRS assigns: not 00, 07-09, 17-19, 28, 29, 49, 69, 70, 78, 79, 89, 96
+///   or 97.
+///
+/// # Returns
+///
+/// * `Result<TaxpayerId, ValidationError>` - Returns the parsed [`TaxpayerId`] if the number is
+///   valid, or the broken rule (a [`TaxpayerIdError`] or an
+///   [`SsnError`](crate::identity::SsnError)) and the span of the offending digits otherwise.
+pub fn validate_taxpayer_id(taxpayer_id: &str) -> Result<TaxpayerId, ValidationError> {
+    let Some(captures) = TAXPAYER_ID_REGEX.captures(taxpayer_id) else {
+        return Err(ValidationError::new(TaxpayerIdError::Malformed, Span::whole(taxpayer_id)));
+    };
+
+    // EINs are the only numbers split 2-7.
+    if let (Some(prefix_match), Some(number_match)) = (captures.name("prefix"), captures.name("number")) {
+        let (Ok(prefix), Ok(serial)) = (prefix_match.as_str().parse::<u8>(), number_match.as_str().parse::<u32>()) else {
+            return Err(ValidationError::new(TaxpayerIdError::Malformed, Span::whole(taxpayer_id)));
+        };
+        if UNASSIGNED_EIN_PREFIXES.contains(&prefix) {
+            return Err(ValidationError::new(TaxpayerIdError::InvalidEinPrefix(prefix), prefix_match));
+        }
+        return Ok(TaxpayerId { kind: TaxpayerIdKind::Ein, number: u32::from(prefix) * 10_000_000 + serial });
+    }
+
+    let area = captures.name("area").unwrap();
+    let group_match = captures.name("group").unwrap();
+    let serial_match = captures.name("serial").unwrap();
+    let (Ok(number), Ok(group), Ok(serial)) = (
+        format!("{}{}{}", area.as_str(), group_match.as_str(), serial_match.as_str()).parse::<u32>(),
+        group_match.as_str().parse::<u8>(),
+        serial_match.as_str().parse::<u16>(),
+    ) else {
+        return Err(ValidationError::new(TaxpayerIdError::Malformed, Span::whole(taxpayer_id)));
+    };
+    if !area.as_str().starts_with('9') {
+        validate_ssn(taxpayer_id)?;
+        return Ok(TaxpayerId { kind: TaxpayerIdKind::Ssn, number });
+    }
+
+    // The IRS issues ITINs and ATINs from the 9xx areas the SSA never uses, telling them apart by group.
+    let kind = match group {
+        93 => TaxpayerIdKind::Atin,
+        50..=65 | 70..=88 | 90..=92 | 94..=99 => TaxpayerIdKind::Itin,
+        _ => return Err(ValidationError::new(TaxpayerIdError::InvalidItinGroup(group), group_match)),
+    };
+    if serial == 0 {
+        return Err(ValidationError::new(TaxpayerIdError::ZeroSerial, serial_match));
+    }
+    Ok(TaxpayerId { kind, number })
+}
+
+/// The [`Validator`] for US taxpayer identification numbers; see [`validate_taxpayer_id`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TaxpayerIdValidator;
+
+impl Validator for TaxpayerIdValidator {
+    fn name(&self) -> &'static str {
+        "taxpayer-id"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "SSNs, ITINs and ATINs are nine digits, optionally separated as AAA-GG-SSSS by dashes or spaces.",
+            "Numbers with an area below 900 are SSNs and follow the SSN rules.",
+            "Numbers with an area of 900-999 are ATINs with group 93, or ITINs with group 50-65, 70-88, 90-92 or 94-99.",
+            "The serial number of ITINs and ATINs (last four digits) must not be 0000.",
+            "EINs are nine digits separated as NN-NNNNNNN, with a prefix the IRS assigns.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_taxpayer_id(input).map(|_| ())
+    }
+
+    /// Normalizes the number to the canonical form of its kind.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        validate_taxpayer_id(input).map(|taxpayer_id| taxpayer_id.to_string())
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        let taxpayer_id = validate_taxpayer_id(input)?;
+        let digits = taxpayer_id.digits();
+        let mut components = vec![("kind", taxpayer_id.kind().name().to_string())];
+        match taxpayer_id.kind() {
+            TaxpayerIdKind::Ein => components.extend([("prefix", digits[..2].to_string()), ("serial", digits[2..].to_string())]),
+            _ => components.extend([
+                ("area", digits[..3].to_string()),
+                ("group", digits[3..5].to_string()),
+                ("serial", digits[5..].to_string()),
+            ]),
+        }
+        Ok(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::identity::SsnError;
+
+    fn kind(taxpayer_id: &str) -> TaxpayerIdKind {
+        validate_taxpayer_id(taxpayer_id).unwrap().kind()
+    }
+
+    #[test]
+    fn test_validate_taxpayer_id_kinds() {
+        assert_eq!(kind("123-45-6788"), TaxpayerIdKind::Ssn);
+        assert_eq!(kind("900-70-0001"), TaxpayerIdKind::Itin);
+        assert_eq!(kind("999 99 9999"), TaxpayerIdKind::Itin);
+        assert_eq!(kind("912-93-1234"), TaxpayerIdKind::Atin);
+        assert_eq!(kind("01-0000001"), TaxpayerIdKind::Ein);
+        assert_eq!(kind("98 7654321"), TaxpayerIdKind::Ein);
+    }
+
+    #[test]
+    fn test_validate_taxpayer_id_itin_groups() {
+        for group in 0..=99u8 {
+            let result = validate_taxpayer_id(&format!("912-{:02}-3456", group));
+            let valid = matches!(group, 50..=65 | 70..=88 | 90..=99);
+            assert_eq!(result.is_ok(), valid, "group {:02}", group);
+        }
+        let err = validate_taxpayer_id("912-66-3456").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::TaxpayerId(TaxpayerIdError::InvalidItinGroup(66)));
+        assert_eq!(err.span(), Span::new(4, 6));
+    }
+
+    #[test]
+    fn test_validate_taxpayer_id_rejects_zero_itin_serial() {
+        for itin in ["912-70-0000", "912-93-0000"] {
+            let err = validate_taxpayer_id(itin).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::TaxpayerId(TaxpayerIdError::ZeroSerial));
+            assert_eq!(err.span(), Span::new(7, 11));
+        }
+    }
+
+    #[test]
+    fn test_validate_taxpayer_id_rejects_non_ascii_digits() {
+        for taxpayer_id in ["٩١٢-٧٠-١٢٣٤", "１２-３４５６７８９"] {
+            let err = validate_taxpayer_id(taxpayer_id).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::TaxpayerId(TaxpayerIdError::Malformed));
+        }
+    }
+
+    #[test]
+    fn test_validate_taxpayer_id_ein_prefixes() {
+        let err = validate_taxpayer_id("07-1234567").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::TaxpayerId(TaxpayerIdError::InvalidEinPrefix(7)));
+        assert_eq!(err.span(), Span::new(0, 2));
+        let assigned = (0..=99u8).filter(|prefix| validate_taxpayer_id(&format!("{:02}-1234567", prefix)).is_ok()).count();
+        assert_eq!(assigned, 83);
+    }
+
+    #[test]
+    fn test_validate_taxpayer_id_applies_ssn_rules() {
+        let err = validate_taxpayer_id("666-12-3456").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Ssn(SsnError::ForbiddenArea(666)));
+        assert!(validate_taxpayer_id("078-05-1120").is_err());
+        let err = validate_taxpayer_id("12-345-6789").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::TaxpayerId(TaxpayerIdError::Malformed));
+        assert!(validate_taxpayer_id("12-34567890").is_err());
+    }
+
+    #[test]
+    fn test_taxpayer_id_canonical_form() {
+        assert_eq!(validate_taxpayer_id("912701234").unwrap().to_string(), "912-70-1234");
+        assert_eq!(validate_taxpayer_id("01 0000001").unwrap().to_string(), "01-0000001");
+        assert_eq!("01-0000001".parse::<TaxpayerId>().unwrap().digits(), "010000001");
+    }
+
+    #[test]
+    fn test_taxpayer_id_validator_components() {
+        let components = TaxpayerIdValidator.components("12-3456789").unwrap();
+        assert_eq!(components, vec![("kind", "EIN".to_string()), ("prefix", "12".to_string()), ("serial", "3456789".to_string())]);
+        assert_eq!(TaxpayerIdValidator.components("912-93-1234").unwrap()[0], ("kind", "ATIN".to_string()));
+    }
+}
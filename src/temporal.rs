@@ -0,0 +1,1268 @@
+//! Dates and times: calendar dates, leap years and military time.
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use regex::Regex;
+
+use crate::error::{Span, ValidationError};
+use crate::validator::Validator;
+
+/// Matches a two-digit month and day and four-digit year separated by dashes or slashes.
+static DATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<month>[0-9]{2})(?P<first_separator>[-/])(?P<day>[0-9]{2})(?P<second_separator>[-/])(?P<year>[0-9]{4})$").unwrap()
+});
+
+/// Matches an ISO 8601 calendar date, `YYYY-MM-DD`, with optional zero padding.
+static ISO_DATE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?P<year>[0-9]{4})-(?P<month>[0-9]{1,2})-(?P<day>[0-9]{1,2})$").unwrap());
+
+/// Matches a numeric date with the month and day in either order, e.g. `3/4/2023` or `03.04.2023`.
+static NUMERIC_DATE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<first>[0-9]{1,2})(?P<first_separator>[-/.])(?P<second>[0-9]{1,2})(?P<second_separator>[-/.])(?P<year>[0-9]{4})$").unwrap()
+});
+
+/// Matches a date with the month name first, e.g. `March 5, 2023` or `Mar. 5th 2023`.
+static MONTH_NAME_FIRST_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<month>[a-zA-Z]+)\.?\s+(?P<day>[0-9]{1,2})(?:st|nd|rd|th)?,?\s+(?P<year>[0-9]{4})$").unwrap()
+});
+
+/// Matches a date with the day first and then the month name, e.g. `5 Mar 2023` or `5th March, 2023`.
+static DAY_FIRST_NAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?P<day>[0-9]{1,2})(?:st|nd|rd|th)?\s+(?P<month>[a-zA-Z]+)\.?,?\s+(?P<year>[0-9]{4})$").unwrap()
+});
+
+/// Matches a credit card expiry date, `MM/YY`.
+static CARD_EXPIRY_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?P<month>[0-9]{2})\s?/\s?(?P<year>[0-9]{2})$").unwrap());
+
+/// The English month names, from January.
+const MONTH_NAMES: [&str; 12] =
+    ["january", "february", "march", "april", "may", "june", "july", "august", "september", "october", "november", "december"];
+
+/// Matches four digits, capturing the hour and minute.
+static MILITARY_TIME_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(?P<hour>[0-9]{2})(?P<minute>[0-9]{2})$").unwrap());
+
+/// The rule a calendar date broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateError {
+    /// The input is not in the form MM-DD-YYYY or MM/DD/YYYY.
+    Malformed,
+    /// The month is not between 01 and 12.
+    MonthOutOfRange(u8),
+    /// The day is 00 or past the last day of the month.
+    DayOutOfRange { month: u8, max: u8 },
+    /// The two separators differ, e.g. `01-02/2023`.
+    MixedSeparators,
+    /// The input is in none of the formats [`parse_date`] reads.
+    UnrecognizedFormat,
+    /// The month name is not an English month name or its three-letter abbreviation.
+    UnknownMonthName(String),
+    /// The numeric date reads as a different valid date with the month or the day first, and
+    /// [`DateOrder::Either`] does not say which is meant.
+    Ambiguous { month_first: UsDate, day_first: UsDate },
+    /// The date is after today, which [`DateConstraints::not_in_future`] rejects.
+    InFuture,
+    /// The date is less than the given number of years before today, which
+    /// [`DateConstraints::at_least_years_ago`] rejects.
+    TooRecent { years: u16 },
+    /// The date is before the earliest date allowed by [`DateConstraints::not_before`].
+    TooEarly(UsDate),
+    /// The date is after the latest date allowed by [`DateConstraints::not_after`].
+    TooLate(UsDate),
+    /// The date falls on a weekend, which [`DateConstraints::business_days_only`] rejects.
+    NotBusinessDay(Weekday),
+    /// The input is not a card expiry date in the form MM/YY.
+    MalformedExpiry,
+    /// The card expired at the end of the given month.
+    Expired { month: u8, year: u16 },
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateError::Malformed => write!(f, "date must be in the form MM-DD-YYYY or MM/DD/YYYY"),
+            DateError::MonthOutOfRange(month) => write!(f, "month {:02} is not between 01 and 12", month),
+            DateError::DayOutOfRange { month, max } => write!(f, "day must be between 01 and {:02} for month {:02}", max, month),
+            DateError::MixedSeparators => write!(f, "date separators must be both dashes or both slashes"),
+            DateError::UnrecognizedFormat => {
+                write!(f, "date must be YYYY-MM-DD, numeric such as 03/04/2023, or have a month name such as March 4, 2023")
+            }
+            DateError::UnknownMonthName(name) => write!(f, "{:?} is not a month name", name),
+            DateError::Ambiguous { month_first, day_first } => {
+                write!(f, "date is ambiguous: {} with the month first, {} with the day first", month_first.iso8601(), day_first.iso8601())
+            }
+            DateError::InFuture => write!(f, "date must not be in the future"),
+            DateError::TooRecent { years } => write!(f, "date must be at least {} years ago", years),
+            DateError::TooEarly(earliest) => write!(f, "date must not be before {}", earliest),
+            DateError::TooLate(latest) => write!(f, "date must not be after {}", latest),
+            DateError::NotBusinessDay(weekday) => write!(f, "date must be a business day, not a {}", weekday),
+            DateError::MalformedExpiry => write!(f, "card expiry must be in the form MM/YY"),
+            DateError::Expired { month, year } => write!(f, "card expired at the end of {:02}/{:04}", month, year),
+        }
+    }
+}
+
+/// The rule a military time broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MilitaryTimeError {
+    /// The input is not exactly four digits.
+    Malformed,
+    /// The hour is not between 00 and 23.
+    HourOutOfRange(u8),
+    /// The minute is not between 00 and 59.
+    MinuteOutOfRange(u8),
+}
+
+impl fmt::Display for MilitaryTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MilitaryTimeError::Malformed => write!(f, "military time must be four digits without a colon, e.g. 0830"),
+            MilitaryTimeError::HourOutOfRange(hour) => write!(f, "hour {:02} is not between 00 and 23", hour),
+            MilitaryTimeError::MinuteOutOfRange(minute) => write!(f, "minute {:02} is not between 00 and 59", minute),
+        }
+    }
+}
+
+/// A valid calendar date.
+///
+/// # Description
+///
+/// * Produced by [`validate_date`] or by parsing a string with [`str::parse`].
+/// * Displays in the US form `MM/DD/YYYY`.
+/// * Orders chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UsDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl UsDate {
+    /// Creates a date, returning `None` if the year has more than four digits or the month or day
+    /// is out of range for the year.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<UsDate> {
+        if year > 9999 {
+            return None;
+        }
+        let max = days_in_month(year, month)?;
+        (1..=max).contains(&day).then_some(UsDate { year, month, day })
+    }
+
+    /// Returns the four-digit year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, from 1.
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the date in the ISO 8601 form `YYYY-MM-DD`.
+    pub fn iso8601(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// Returns today's date in UTC, from the system clock.
+    pub fn today() -> UsDate {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+        UsDate::from_days_since_epoch(seconds as i64 / 86_400)
+    }
+
+    /// Returns the day of the week.
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday, the fourth day of a week starting on Monday.
+        Weekday::ALL[(self.days_since_epoch() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Returns the same day `years` years earlier, or February 28 for February 29 in a year that
+    /// is not a leap year. Returns `None` before year 0.
+    pub fn years_before(&self, years: u16) -> Option<UsDate> {
+        let year = self.year.checked_sub(years)?;
+        Some(UsDate { year, month: self.month, day: self.day.min(days_in_month(year, self.month)?) })
+    }
+
+    /// Returns the number of days from 1970-01-01 to the date.
+    fn days_since_epoch(&self) -> i64 {
+        // Counts from March 1 of year 0, so the leap day is the last day of each counted year.
+        let (year, month, day) = (i64::from(self.year), i64::from(self.month), i64::from(self.day));
+        let year = if month <= 2 { year - 1 } else { year };
+        let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let leap_days = year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400);
+        365 * year + leap_days + day_of_year + 60 - 719_528
+    }
+
+    /// Returns the date `days` days after 1970-01-01, the inverse of [`UsDate::days_since_epoch`].
+    fn from_days_since_epoch(days: i64) -> UsDate {
+        // Steps forward one year at a time from an estimate below the year, since years are 365
+        // or 366 days long.
+        let mut year = (days + 719_528).div_euclid(366) - 1;
+        while UsDate::march_first(year + 1) <= days {
+            year += 1;
+        }
+        let day_of_year = days - UsDate::march_first(year);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = if month <= 2 { year + 1 } else { year };
+        UsDate { year: year.clamp(0, 9999) as u16, month: month as u8, day: day as u8 }
+    }
+
+    /// Returns the number of days from 1970-01-01 to March 1 of `year`.
+    fn march_first(year: i64) -> i64 {
+        365 * year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400) + 60 - 719_528
+    }
+}
+
+impl fmt::Display for UsDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}/{:04}", self.month, self.day, self.year)
+    }
+}
+
+impl FromStr for UsDate {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<UsDate, ValidationError> {
+        validate_date(s)
+    }
+}
+
+/// A valid 24-hour military time.
+///
+/// # Description
+///
+/// * Produced by [`validate_military_time`] or by parsing a string with [`str::parse`].
+/// * Displays as four digits without a colon, e.g. `0832`.
+/// * Orders from `0000` to `2359`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MilitaryTime {
+    hour: u8,
+    minute: u8,
+}
+
+impl MilitaryTime {
+    /// Creates a time, returning `None` if the hour is over 23 or the minute over 59.
+    pub fn new(hour: u8, minute: u8) -> Option<MilitaryTime> {
+        (hour <= 23 && minute <= 59).then_some(MilitaryTime { hour, minute })
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+}
+
+impl fmt::Display for MilitaryTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}", self.hour, self.minute)
+    }
+}
+
+impl FromStr for MilitaryTime {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<MilitaryTime, ValidationError> {
+        validate_military_time(s)
+    }
+}
+
+/// Validates a given date string in the format "MM/DD/YYYY" or "MM-DD-YYYY".
+///
+/// # Description
+///
+/// * Checks if the date string matches the expected format using a regex pattern, with the same
+///   separator twice. [`parse_date`] reads other formats.
+/// * Ensures the day value is valid for the given month and year.
+/// * Accounts for leap years.
+///
+/// # Arguments
+///
+/// * `date: &str` - The input date string to validate.
+///
+/// # Returns
+///
+/// * `Result<UsDate, ValidationError>` - Returns the parsed [`UsDate`] if the date is valid, or the broken
+///   rule (a [`DateError`]) and the span of the offending field otherwise.
+pub fn validate_date(date: &str) -> Result<UsDate, ValidationError> {
+    let Some(captures) = DATE_REGEX.captures(date) else {
+        return Err(ValidationError::new(DateError::Malformed, Span::whole(date)));
+    };
+
+    // Both separators must be the same, so 01-02/2023 is rejected.
+    let first_separator = captures.name("first_separator").unwrap();
+    let second_separator = captures.name("second_separator").unwrap();
+    if first_separator.as_str() != second_separator.as_str() {
+        return Err(ValidationError::new(DateError::MixedSeparators, second_separator));
+    }
+
+    // Parses the month, day, and year values from the matched date string into integers.
+    let month_match = captures.name("month").unwrap();
+    let day_match = captures.name("day").unwrap();
+    let (Ok(month), Ok(day), Ok(year)) =
+        (month_match.as_str().parse::<u8>(), day_match.as_str().parse::<u8>(), captures["year"].parse::<u16>())
+    else {
+        return Err(ValidationError::new(DateError::Malformed, Span::whole(date)));
+    };
+    check_date(year, (month, month_match.into()), (day, day_match.into()))
+}
+
+/// Returns the date `year`-`month`-`day`, or the range rule it broke with the span of the
+/// offending month or day.
+fn check_date(year: u16, (month, month_span): (u8, Span), (day, day_span): (u8, Span)) -> Result<UsDate, ValidationError> {
+    // Determines the number of days in the given month.
+    let Some(days_in_month) = days_in_month(year, month) else {
+        return Err(ValidationError::new(DateError::MonthOutOfRange(month), month_span));
+    };
+
+    // Checks if the day value is valid for the given month and year.
+    if day == 0 || day > days_in_month {
+        return Err(ValidationError::new(DateError::DayOutOfRange { month, max: days_in_month }, day_span));
+    }
+    Ok(UsDate { year, month, day })
+}
+
+/// Which order the month and day of a numeric date such as `03/04/2023` are read in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum DateOrder {
+    /// The US order, month first: `03/04/2023` is March 4.
+    #[default]
+    MonthFirst,
+    /// The order of most other locales, day first: `03/04/2023` is April 3.
+    DayFirst,
+    /// Either order: dates valid in only one order are read in that order, and dates valid in
+    /// both but meaning different days are rejected as [`DateError::Ambiguous`].
+    Either,
+}
+
+/// The format [`parse_date`] read a date in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateFormat {
+    /// `YYYY-MM-DD`, e.g. `2023-03-04`.
+    Iso8601,
+    /// Numeric with the month first, e.g. `3/4/2023`.
+    MonthDayYear,
+    /// Numeric with the day first, e.g. `4.3.2023`.
+    DayMonthYear,
+    /// The month name first, e.g. `March 4, 2023`.
+    MonthNameDayYear,
+    /// The day first and then the month name, e.g. `4 Mar 2023`.
+    DayMonthNameYear,
+}
+
+impl DateFormat {
+    /// Returns the name of the format, e.g. `iso8601` or `month-day-year`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DateFormat::Iso8601 => "iso8601",
+            DateFormat::MonthDayYear => "month-day-year",
+            DateFormat::DayMonthYear => "day-month-year",
+            DateFormat::MonthNameDayYear => "month-name-day-year",
+            DateFormat::DayMonthNameYear => "day-month-name-year",
+        }
+    }
+}
+
+/// A date read by [`parse_date`], with how it was written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsedDate {
+    date: UsDate,
+    format: DateFormat,
+    alternative: Option<UsDate>,
+}
+
+impl ParsedDate {
+    /// Returns the date.
+    pub fn date(&self) -> UsDate {
+        self.date
+    }
+
+    /// Returns the format the date was written in.
+    pub fn format(&self) -> DateFormat {
+        self.format
+    }
+
+    /// Returns the date the input would mean with the month and day the other way round, if that
+    /// is a different valid date, e.g. April 3 for `03/04/2023` read month first.
+    pub fn alternative(&self) -> Option<UsDate> {
+        self.alternative
+    }
+
+    /// Returns whether the input could also be read as a different date; see
+    /// [`ParsedDate::alternative`].
+    pub fn is_ambiguous(&self) -> bool {
+        self.alternative.is_some()
+    }
+}
+
+/// Parses a date written in any of the common formats.
+///
+/// # Arguments
+///
+/// * `date` - A string slice that holds the date.
+/// * `order` - Which order to read the month and day of numeric dates in.
+///
+/// # Rules
+///
+/// * ISO 8601 dates, `YYYY-MM-DD`.
+/// * Numeric dates with the month and day in the order of `order`, separated by the same dash,
+///   slash or dot, e.g. `03/04/2023` or `4.3.2023`.
+/// * Dates with an English month name or its three-letter abbreviation, either first, as in
+///   `March 5, 2023`, or after the day, as in `5 Mar 2023`. Case, a dot after an abbreviation,
+///   an ordinal suffix (`5th`) and a comma before the year are all optional.
+/// * Months and days need no zero padding; years have four digits.
+/// * The day must exist in the month, accounting for leap years.
+///
+/// # Returns
+///
+/// * `Result<ParsedDate, ValidationError>` - Returns the [`ParsedDate`], which reports whether the
+///   other order would give a different date, or the broken rule (a [`DateError`]) and the span of
+///   the offending field otherwise.
+pub fn parse_date(date: &str, order: DateOrder) -> Result<ParsedDate, ValidationError> {
+    let unrecognized = || ValidationError::new(DateError::UnrecognizedFormat, Span::whole(date));
+    let year_of = |captures: &regex::Captures| captures["year"].parse::<u16>().map_err(|_| unrecognized());
+    let number_of = |captures: &regex::Captures, name: &str| {
+        let number = captures.name(name).unwrap();
+        number.as_str().parse::<u8>().map(|value| (value, Span::from(number))).map_err(|_| unrecognized())
+    };
+
+    if let Some(captures) = ISO_DATE_REGEX.captures(date) {
+        let date = check_date(year_of(&captures)?, number_of(&captures, "month")?, number_of(&captures, "day")?)?;
+        return Ok(ParsedDate { date, format: DateFormat::Iso8601, alternative: None });
+    }
+
+    for (regex, format) in [(&MONTH_NAME_FIRST_REGEX, DateFormat::MonthNameDayYear), (&DAY_FIRST_NAME_REGEX, DateFormat::DayMonthNameYear)] {
+        if let Some(captures) = regex.captures(date) {
+            let name = captures.name("month").unwrap();
+            let Some(month) = month_number(name.as_str()) else {
+                return Err(ValidationError::new(DateError::UnknownMonthName(name.as_str().to_string()), name));
+            };
+            let date = check_date(year_of(&captures)?, (month, name.into()), number_of(&captures, "day")?)?;
+            return Ok(ParsedDate { date, format, alternative: None });
+        }
+    }
+
+    let Some(captures) = NUMERIC_DATE_REGEX.captures(date) else {
+        return Err(ValidationError::new(DateError::UnrecognizedFormat, Span::whole(date)));
+    };
+    let second_separator = captures.name("second_separator").unwrap();
+    if captures["first_separator"] != *second_separator.as_str() {
+        return Err(ValidationError::new(DateError::MixedSeparators, second_separator));
+    }
+    let (year, first, second) = (year_of(&captures)?, number_of(&captures, "first")?, number_of(&captures, "second")?);
+    let month_first = check_date(year, first, second);
+    let day_first = check_date(year, second, first);
+    let different = |date: &UsDate, other: &Result<UsDate, ValidationError>| other.as_ref().ok().filter(|other| *other != date).copied();
+    match order {
+        DateOrder::MonthFirst => {
+            let date = month_first?;
+            Ok(ParsedDate { date, format: DateFormat::MonthDayYear, alternative: different(&date, &day_first) })
+        }
+        DateOrder::DayFirst => {
+            let date = day_first?;
+            Ok(ParsedDate { date, format: DateFormat::DayMonthYear, alternative: different(&date, &month_first) })
+        }
+        DateOrder::Either => match (month_first, day_first) {
+            (Ok(month_first), Ok(day_first)) if month_first != day_first => {
+                Err(ValidationError::new(DateError::Ambiguous { month_first, day_first }, Span::whole(date)))
+            }
+            (Ok(date), _) => Ok(ParsedDate { date, format: DateFormat::MonthDayYear, alternative: None }),
+            (Err(_), Ok(date)) => Ok(ParsedDate { date, format: DateFormat::DayMonthYear, alternative: None }),
+            (Err(err), Err(_)) => Err(err),
+        },
+    }
+}
+
+/// Returns the number of the month named `name`, in full or by its first three letters (or
+/// `Sept`), ignoring case.
+fn month_number(name: &str) -> Option<u8> {
+    let name = name.to_ascii_lowercase();
+    let index = MONTH_NAMES
+        .iter()
+        .position(|month| *month == name || (name.len() == 3 && month.starts_with(&name)) || (name == "sept" && *month == "september"))?;
+    Some(index as u8 + 1)
+}
+
+/// Returns the number of days in `month` of `year`, accounting for leap years.
+///
+/// # Returns
+///
+/// * `Option<u8>` - Returns the number of days, or `None` if `month` is not between 1 and 12.
+pub fn days_in_month(year: u16, month: u8) -> Option<u8> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 if is_leap_year(year) => Some(29),
+        2 => Some(28),
+        _ => None,
+    }
+}
+
+/// Determines if a given year is a leap year.
+///
+/// # Description
+///
+/// * A leap year is divisible by 4, but not divisible by 100 unless it's also divisible by 400.
+///
+/// # Arguments
+///
+/// * `year: u16` - The input year to check for leap year status.
+///
+/// # Returns
+///
+/// * `bool` - Returns `true` if the year is a leap year, and `false` otherwise.
+pub fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+/// Validates a military time (24-hour) format string by checking if it matches the expected format.
+///
+/// # Description
+///
+/// * This function checks if the input string matches the military time format (24-hour format), which consists of:
+///   - Hours: 00-23
+///   - Minutes: 00-59
+/// * The input string should be 4 digits long without any separator between hours and minutes.
+///
+/// # Arguments
+///
+/// * `time: &str` - The input string containing the military time to validate.
+///
+/// # Returns
+///
+/// * `Result<MilitaryTime, ValidationError>` - Returns the parsed [`MilitaryTime`] if the input matches the
+///   expected military time format, or the broken rule (a [`MilitaryTimeError`]) otherwise.
+pub fn validate_military_time(time: &str) -> Result<MilitaryTime, ValidationError> {
+    let Some(captures) = MILITARY_TIME_REGEX.captures(time) else {
+        return Err(ValidationError::new(MilitaryTimeError::Malformed, Span::whole(time)));
+    };
+
+    let hour_match = captures.name("hour").unwrap();
+    let minute_match = captures.name("minute").unwrap();
+    let (Ok(hour), Ok(minute)) = (hour_match.as_str().parse::<u8>(), minute_match.as_str().parse::<u8>()) else {
+        return Err(ValidationError::new(MilitaryTimeError::Malformed, Span::whole(time)));
+    };
+
+    // Hours run from 00 to 23 and minutes from 00 to 59.
+    if hour > 23 {
+        return Err(ValidationError::new(MilitaryTimeError::HourOutOfRange(hour), hour_match));
+    }
+    if minute > 59 {
+        return Err(ValidationError::new(MilitaryTimeError::MinuteOutOfRange(minute), minute_match));
+    }
+    Ok(MilitaryTime { hour, minute })
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Every day of the week, from Monday.
+    pub const ALL: [Weekday; 7] =
+        [Weekday::Monday, Weekday::Tuesday, Weekday::Wednesday, Weekday::Thursday, Weekday::Friday, Weekday::Saturday, Weekday::Sunday];
+
+    /// Returns whether the day is a Saturday or Sunday.
+    pub fn is_weekend(&self) -> bool {
+        matches!(self, Weekday::Saturday | Weekday::Sunday)
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Constraints on a valid date relative to today, e.g. for birthdates or appointment dates.
+///
+/// # Description
+///
+/// * Each builder method adds one constraint; a date must meet all of them.
+/// * Today is read from the system clock in UTC when the constraints are created, and can be set
+///   with [`DateConstraints::with_today`], e.g. for tests or another time zone.
+///
+/// ```
+/// use regular_expressions::temporal::{DateConstraints, UsDate};
+///
+/// let birthdate = DateConstraints::new().with_today(UsDate::new(2026, 3, 1).unwrap()).not_in_future().at_least_years_ago(18);
+/// assert!(birthdate.validate("02/29/2008").is_ok());
+/// assert!(birthdate.validate("03/02/2008").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateConstraints {
+    today: UsDate,
+    not_in_future: bool,
+    min_years_ago: Option<u16>,
+    earliest: Option<UsDate>,
+    latest: Option<UsDate>,
+    business_days_only: bool,
+}
+
+impl DateConstraints {
+    /// Creates constraints that every valid date meets, with today read from the system clock.
+    pub fn new() -> DateConstraints {
+        DateConstraints {
+            today: UsDate::today(),
+            not_in_future: false,
+            min_years_ago: None,
+            earliest: None,
+            latest: None,
+            business_days_only: false,
+        }
+    }
+
+    /// Sets the date that relative constraints count from.
+    pub fn with_today(mut self, today: UsDate) -> DateConstraints {
+        self.today = today;
+        self
+    }
+
+    /// Rejects dates after today.
+    pub fn not_in_future(mut self) -> DateConstraints {
+        self.not_in_future = true;
+        self
+    }
+
+    /// Rejects dates less than `years` years before today, e.g. birthdates of people younger than
+    /// 18. Someone born on February 29 turns a year older on March 1 in other years.
+    pub fn at_least_years_ago(mut self, years: u16) -> DateConstraints {
+        self.min_years_ago = Some(years);
+        self
+    }
+
+    /// Rejects dates before `earliest`.
+    pub fn not_before(mut self, earliest: UsDate) -> DateConstraints {
+        self.earliest = Some(earliest);
+        self
+    }
+
+    /// Rejects dates after `latest`.
+    pub fn not_after(mut self, latest: UsDate) -> DateConstraints {
+        self.latest = Some(latest);
+        self
+    }
+
+    /// Rejects Saturdays and Sundays. Holidays are not known, so they are allowed.
+    pub fn business_days_only(mut self) -> DateConstraints {
+        self.business_days_only = true;
+        self
+    }
+
+    /// Returns the date relative constraints count from.
+    pub fn today(&self) -> UsDate {
+        self.today
+    }
+
+    /// Checks `date` against the constraints.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), DateError>` - Returns `Ok(())` if the date meets every constraint, or the
+    ///   first one it breaks otherwise.
+    pub fn check(&self, date: UsDate) -> Result<(), DateError> {
+        if self.not_in_future && date > self.today {
+            return Err(DateError::InFuture);
+        }
+        if let Some(years) = self.min_years_ago {
+            if self.today.years_before(years).is_none_or(|latest| date > latest) {
+                return Err(DateError::TooRecent { years });
+            }
+        }
+        if let Some(earliest) = self.earliest.filter(|earliest| date < *earliest) {
+            return Err(DateError::TooEarly(earliest));
+        }
+        if let Some(latest) = self.latest.filter(|latest| date > *latest) {
+            return Err(DateError::TooLate(latest));
+        }
+        if self.business_days_only && date.weekday().is_weekend() {
+            return Err(DateError::NotBusinessDay(date.weekday()));
+        }
+        Ok(())
+    }
+
+    /// Validates `date` with [`validate_date`] and checks it against the constraints.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<UsDate, ValidationError>` - Returns the parsed [`UsDate`] if it is valid and meets
+    ///   every constraint, or the broken rule (a [`DateError`]) otherwise. Broken constraints span
+    ///   the whole input.
+    pub fn validate(&self, date: &str) -> Result<UsDate, ValidationError> {
+        let parsed = validate_date(date)?;
+        self.check(parsed).map_err(|err| ValidationError::new(err, Span::whole(date)))?;
+        Ok(parsed)
+    }
+}
+
+/// Reads today from the system clock; see [`DateConstraints::new`].
+impl Default for DateConstraints {
+    fn default() -> DateConstraints {
+        DateConstraints::new()
+    }
+}
+
+/// The month and year a payment card expires at the end of.
+///
+/// # Description
+///
+/// * Produced by [`validate_card_expiry`].
+/// * Displays in the form printed on cards, `MM/YY`.
+/// * Orders chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CardExpiry {
+    year: u16,
+    month: u8,
+}
+
+impl CardExpiry {
+    /// Returns the four-digit year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the last day the card is valid on, the last day of its month.
+    pub fn last_day(&self) -> UsDate {
+        UsDate { year: self.year, month: self.month, day: days_in_month(self.year, self.month).unwrap() }
+    }
+}
+
+impl fmt::Display for CardExpiry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}/{:02}", self.month, self.year % 100)
+    }
+}
+
+/// Validates the expiry date of a payment card.
+///
+/// # Arguments
+///
+/// * `expiry` - A string slice that holds the expiry date in the form MM/YY.
+/// * `today` - The date to check the expiry against, e.g. [`UsDate::today`].
+///
+/// # Rules
+///
+/// * Two-digit month and year separated by a slash, optionally with a space on either side.
+/// * The month must be between 01 and 12; the year is read as 20YY.
+/// * The card is valid through the last day of its month, so it must not have ended before `today`.
+///
+/// # Returns
+///
+/// * `Result<CardExpiry, ValidationError>` - Returns the parsed [`CardExpiry`] if the card has not
+///   expired, or the broken rule (a [`DateError`]) and the span of the offending field otherwise.
+pub fn validate_card_expiry(expiry: &str, today: UsDate) -> Result<CardExpiry, ValidationError> {
+    let Some(captures) = CARD_EXPIRY_REGEX.captures(expiry) else {
+        return Err(ValidationError::new(DateError::MalformedExpiry, Span::whole(expiry)));
+    };
+    let month_match = captures.name("month").unwrap();
+    let (Ok(month), Ok(year)) = (month_match.as_str().parse::<u8>(), captures["year"].parse::<u16>()) else {
+        return Err(ValidationError::new(DateError::MalformedExpiry, Span::whole(expiry)));
+    };
+    let year = 2000 + year;
+    if !(1..=12).contains(&month) {
+        return Err(ValidationError::new(DateError::MonthOutOfRange(month), month_match));
+    }
+    let card_expiry = CardExpiry { year, month };
+    if card_expiry.last_day() < today {
+        return Err(ValidationError::new(DateError::Expired { month, year }, Span::whole(expiry)));
+    }
+    Ok(card_expiry)
+}
+
+/// The [`Validator`] for calendar dates; see [`validate_date`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DateValidator;
+
+impl Validator for DateValidator {
+    fn name(&self) -> &'static str {
+        "date"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "In the format MM-DD-YYYY or MM/DD/YYYY, with the same separator twice.",
+            "The month must be between 01 and 12.",
+            "The day must exist in the given month, accounting for leap years.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_date(input).map(|_| ())
+    }
+
+    /// Normalizes the separators to slashes, e.g. `12-31-2021` becomes `12/31/2021`.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        validate_date(input).map(|date| date.to_string())
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        let date = validate_date(input)?;
+        Ok(vec![
+            ("month", date.month().to_string()),
+            ("day", date.day().to_string()),
+            ("year", date.year().to_string()),
+        ])
+    }
+}
+
+/// The [`Validator`] for military times; see [`validate_military_time`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MilitaryTimeValidator;
+
+impl Validator for MilitaryTimeValidator {
+    fn name(&self) -> &'static str {
+        "military-time"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "Four digits without a colon, with a leading zero for hours under 10.",
+            "Hours must be between 00 and 23.",
+            "Minutes must be between 00 and 59.",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        validate_military_time(input).map(|_| ())
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        let time = validate_military_time(input)?;
+        Ok(vec![("hour", time.hour().to_string()), ("minute", time.minute().to_string())])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use crate::generate::{Field, Generator};
+
+    #[test]
+    fn test_validate_date_valid_with_dash_separator() {
+        assert!(validate_date("12-31-2021").is_ok());
+    }
+
+    #[test]
+    fn test_validate_date_valid_with_slash_separator() {
+        assert!(validate_date("01/01/2022").is_ok());
+    }
+
+    #[test]
+    fn test_validate_date_valid_leap_year() {
+        assert!(validate_date("02-29-2020").is_ok());
+    }
+
+    #[test]
+    fn test_validate_date_valid_month_boundary() {
+        assert!(validate_date("03/31/2021").is_ok());
+    }
+
+    #[test]
+    fn test_validate_date_valid_end_of_year() {
+        assert!(validate_date("12-31-2023").is_ok());
+    }
+
+    #[test]
+    fn test_validate_date_invalid_missing_separator() {
+        assert!(validate_date("03252021").is_err());
+    }
+
+    #[test]
+    fn test_validate_date_invalid_wrong_separator() {
+        assert!(validate_date("04.26.2021").is_err());
+    }
+
+    #[test]
+    fn test_validate_date_rejects_non_ascii_digits() {
+        let err = validate_date("０３/０５/２０２３").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::Malformed));
+    }
+
+    #[test]
+    fn test_validate_date_invalid_day_out_of_range() {
+        assert!(validate_date("02-30-2021").is_err());
+    }
+
+    #[test]
+    fn test_validate_date_invalid_month_out_of_range() {
+        assert!(validate_date("13/01/2021").is_err());
+    }
+
+    #[test]
+    fn test_validate_date_invalid_non_leap_year() {
+        assert!(validate_date("02-29-2021").is_err());
+    }
+
+    #[test]
+    fn test_validate_military_time_valid_midnight() {
+        assert!(validate_military_time("0000").is_ok());
+    }
+
+    #[test]
+    fn test_validate_military_time_valid_noon() {
+        assert!(validate_military_time("1200").is_ok());
+    }
+
+    #[test]
+    fn test_validate_military_time_valid_one_minute_before_midnight() {
+        assert!(validate_military_time("2359").is_ok());
+    }
+
+    #[test]
+    fn test_validate_military_time_valid_random_hour_and_minute() {
+        assert!(validate_military_time("1543").is_ok());
+    }
+
+    #[test]
+    fn test_validate_military_time_valid_leading_zero() {
+        assert!(validate_military_time("0832").is_ok());
+    }
+
+    #[test]
+    fn test_validate_military_time_invalid_too_short() {
+        assert!(validate_military_time("230").is_err());
+    }
+
+    #[test]
+    fn test_validate_military_time_invalid_too_long() {
+        assert!(validate_military_time("13452").is_err());
+    }
+
+    #[test]
+    fn test_validate_military_time_invalid_hour_out_of_range() {
+        assert!(validate_military_time("2500").is_err());
+    }
+
+    #[test]
+    fn test_validate_military_time_invalid_minute_out_of_range() {
+        assert!(validate_military_time("2370").is_err());
+    }
+
+    #[test]
+    fn test_validate_military_time_invalid_non_numeric() {
+        assert!(validate_military_time("abcd").is_err());
+    }
+
+    #[test]
+    fn test_validate_military_time_rejects_non_ascii_digits() {
+        let err = validate_military_time("١٢٣٤").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MilitaryTime(MilitaryTimeError::Malformed));
+    }
+
+    #[test]
+    fn test_validate_date_reports_day_out_of_range() {
+        let err = validate_date("02-30-2021").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::DayOutOfRange { month: 2, max: 28 }));
+        assert_eq!(err.span(), Span::new(3, 5));
+    }
+
+    #[test]
+    fn test_validate_date_reports_leap_day_limit() {
+        let err = validate_date("02/30/2020").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::DayOutOfRange { month: 2, max: 29 }));
+    }
+
+    #[test]
+    fn test_validate_date_reports_month_out_of_range() {
+        let err = validate_date("13/01/2021").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::MonthOutOfRange(13)));
+        assert_eq!(err.span(), Span::new(0, 2));
+    }
+
+    #[test]
+    fn test_validate_military_time_reports_minute_out_of_range() {
+        let err = validate_military_time("2370").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MilitaryTime(MilitaryTimeError::MinuteOutOfRange(70)));
+        assert_eq!(err.span(), Span::new(2, 4));
+    }
+
+    #[test]
+    fn test_validate_military_time_reports_hour_out_of_range() {
+        let err = validate_military_time("2500").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::MilitaryTime(MilitaryTimeError::HourOutOfRange(25)));
+    }
+
+    #[test]
+    fn test_date_validator_normalizes_separators() {
+        assert_eq!(DateValidator.normalize("12-31-2021").unwrap(), "12/31/2021");
+    }
+
+    #[test]
+    fn test_validate_date_returns_parts() {
+        let date = validate_date("02-29-2020").unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2020, 2, 29));
+    }
+
+    #[test]
+    fn test_us_date_display_round_trips() {
+        let date: UsDate = "12-31-2021".parse().unwrap();
+        assert_eq!(date.to_string(), "12/31/2021");
+        assert_eq!(date.to_string().parse::<UsDate>().unwrap(), date);
+    }
+
+    #[test]
+    fn test_us_date_orders_chronologically() {
+        let mut dates: Vec<UsDate> = ["01/01/2022", "12/31/2021", "02/01/2021"].iter().map(|s| s.parse().unwrap()).collect();
+        dates.sort();
+        let sorted: Vec<String> = dates.iter().map(UsDate::to_string).collect();
+        assert_eq!(sorted, vec!["02/01/2021", "12/31/2021", "01/01/2022"]);
+    }
+
+    #[test]
+    fn test_us_date_new_checks_leap_years() {
+        assert!(UsDate::new(2020, 2, 29).is_some());
+        assert!(UsDate::new(1900, 2, 29).is_none());
+        assert!(UsDate::new(2000, 2, 29).is_some());
+        assert!(UsDate::new(2021, 13, 1).is_none());
+    }
+
+    #[test]
+    fn test_validate_military_time_returns_parts() {
+        let time = validate_military_time("0832").unwrap();
+        assert_eq!((time.hour(), time.minute()), (8, 32));
+        assert_eq!(time.to_string(), "0832");
+    }
+
+    #[test]
+    fn test_military_time_orders_through_the_day() {
+        let early: MilitaryTime = "0059".parse().unwrap();
+        let late: MilitaryTime = "2300".parse().unwrap();
+        assert!(early < late);
+        assert_eq!(MilitaryTime::new(23, 0), Some(late));
+        assert!(MilitaryTime::new(24, 0).is_none());
+    }
+
+    #[test]
+    fn test_date_validator_components() {
+        let components = DateValidator.components("02/29/2020").unwrap();
+        assert_eq!(components, vec![("month", "2".to_string()), ("day", "29".to_string()), ("year", "2020".to_string())]);
+    }
+
+    #[test]
+    fn test_validate_date_rejects_mixed_separators() {
+        let err = validate_date("01-02/2023").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::MixedSeparators));
+        assert_eq!(err.span(), Span::new(5, 6));
+        assert!(validate_date("01/02-2023").is_err());
+    }
+
+    fn parsed(date: &str, order: DateOrder) -> (String, DateFormat) {
+        let parsed = parse_date(date, order).unwrap();
+        (parsed.date().iso8601(), parsed.format())
+    }
+
+    #[test]
+    fn test_parse_date_formats() {
+        let order = DateOrder::MonthFirst;
+        assert_eq!(parsed("2023-03-05", order), ("2023-03-05".to_string(), DateFormat::Iso8601));
+        assert_eq!(parsed("2023-3-5", order), ("2023-03-05".to_string(), DateFormat::Iso8601));
+        assert_eq!(parsed("3/5/2023", order), ("2023-03-05".to_string(), DateFormat::MonthDayYear));
+        assert_eq!(parsed("5.3.2023", DateOrder::DayFirst), ("2023-03-05".to_string(), DateFormat::DayMonthYear));
+        assert_eq!(parsed("March 5, 2023", order), ("2023-03-05".to_string(), DateFormat::MonthNameDayYear));
+        assert_eq!(parsed("mar. 5th 2023", order), ("2023-03-05".to_string(), DateFormat::MonthNameDayYear));
+        assert_eq!(parsed("5 Mar 2023", order), ("2023-03-05".to_string(), DateFormat::DayMonthNameYear));
+        assert_eq!(parsed("5th September, 2023", order), ("2023-09-05".to_string(), DateFormat::DayMonthNameYear));
+        assert_eq!(parsed("Sept 5 2023", order), ("2023-09-05".to_string(), DateFormat::MonthNameDayYear));
+    }
+
+    #[test]
+    fn test_parse_date_reports_ambiguity() {
+        let month_first = parse_date("03/04/2023", DateOrder::MonthFirst).unwrap();
+        assert_eq!(month_first.date(), UsDate::new(2023, 3, 4).unwrap());
+        assert_eq!(month_first.alternative(), UsDate::new(2023, 4, 3));
+        let day_first = parse_date("03/04/2023", DateOrder::DayFirst).unwrap();
+        assert_eq!(day_first.date(), UsDate::new(2023, 4, 3).unwrap());
+        assert!(day_first.is_ambiguous());
+
+        let err = parse_date("03/04/2023", DateOrder::Either).unwrap_err();
+        let (month_first, day_first) = (UsDate::new(2023, 3, 4).unwrap(), UsDate::new(2023, 4, 3).unwrap());
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::Ambiguous { month_first, day_first }));
+        assert!(!parse_date("04/04/2023", DateOrder::Either).unwrap().is_ambiguous());
+        assert!(!parse_date("13/04/2023", DateOrder::DayFirst).unwrap().is_ambiguous());
+        assert_eq!(parsed("13/04/2023", DateOrder::Either), ("2023-04-13".to_string(), DateFormat::DayMonthYear));
+        assert_eq!(parsed("04/13/2023", DateOrder::Either), ("2023-04-13".to_string(), DateFormat::MonthDayYear));
+    }
+
+    #[test]
+    fn test_parse_date_reports_broken_rules() {
+        let err = parse_date("13/04/2023", DateOrder::MonthFirst).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::MonthOutOfRange(13)));
+        let err = parse_date("Mrach 5, 2023", DateOrder::MonthFirst).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::UnknownMonthName("Mrach".to_string())));
+        assert_eq!(err.span(), Span::new(0, 5));
+        let err = parse_date("Feb 30, 2023", DateOrder::MonthFirst).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::DayOutOfRange { month: 2, max: 28 }));
+        assert_eq!(err.span(), Span::new(4, 6));
+        let err = parse_date("3/4-2023", DateOrder::MonthFirst).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::MixedSeparators));
+        let err = parse_date("30/30/2023", DateOrder::Either).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::MonthOutOfRange(30)));
+        for date in ["2023/03/05", "March 2023", "5 3 2023", "03/05/23"] {
+            let err = parse_date(date, DateOrder::Either).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Date(DateError::UnrecognizedFormat), "{}", date);
+        }
+    }
+
+    #[test]
+    fn test_parse_date_rejects_non_ascii_digits() {
+        for date in ["٢٠٢٣-03-05", "3/٤/2023", "March ５, 2023", "5 Mar ２０２３"] {
+            let err = parse_date(date, DateOrder::MonthFirst).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Date(DateError::UnrecognizedFormat), "{}", date);
+        }
+    }
+
+    fn date(year: u16, month: u8, day: u8) -> UsDate {
+        UsDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_weekday_of_known_dates() {
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(2000, 2, 29).weekday(), Weekday::Tuesday);
+        assert_eq!(date(2024, 3, 9).weekday(), Weekday::Saturday);
+        assert_eq!(date(1, 1, 1).weekday(), Weekday::Monday);
+        assert_eq!(date(9999, 12, 31).weekday(), Weekday::Friday);
+    }
+
+    #[test]
+    fn test_days_since_epoch_round_trips() {
+        for days in (-719_528..2_932_896).step_by(997) {
+            assert_eq!(UsDate::from_days_since_epoch(days).days_since_epoch(), days);
+        }
+        assert_eq!(UsDate::from_days_since_epoch(0), date(1970, 1, 1));
+        assert_eq!(UsDate::from_days_since_epoch(11_016), date(2000, 2, 29));
+    }
+
+    #[test]
+    fn test_years_before_clamps_leap_day() {
+        assert_eq!(date(2024, 2, 29).years_before(1), Some(date(2023, 2, 28)));
+        assert_eq!(date(2024, 2, 29).years_before(4), Some(date(2020, 2, 29)));
+        assert_eq!(date(2024, 6, 1).years_before(2025), None);
+    }
+
+    #[test]
+    fn test_constraints_reject_future_dates() {
+        let constraints = DateConstraints::new().with_today(date(2024, 6, 1)).not_in_future();
+        assert!(constraints.validate("06/01/2024").is_ok());
+        let err = constraints.validate("06/02/2024").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::InFuture));
+        assert_eq!(err.span(), Span::new(0, 10));
+    }
+
+    #[test]
+    fn test_constraints_gate_age() {
+        let constraints = DateConstraints::new().with_today(date(2024, 6, 1)).at_least_years_ago(18);
+        assert!(constraints.check(date(2006, 6, 1)).is_ok());
+        assert_eq!(constraints.check(date(2006, 6, 2)), Err(DateError::TooRecent { years: 18 }));
+    }
+
+    #[test]
+    fn test_constraints_gate_age_of_leap_day_birthdays() {
+        let birthdate = date(2004, 2, 29);
+        let adult = DateConstraints::new().at_least_years_ago(21);
+        assert!(adult.clone().with_today(date(2025, 3, 1)).check(birthdate).is_ok());
+        assert!(adult.clone().with_today(date(2025, 2, 28)).check(birthdate).is_err());
+        assert!(adult.with_today(date(2024, 2, 29)).check(date(2003, 2, 28)).is_ok());
+    }
+
+    #[test]
+    fn test_constraints_enforce_window() {
+        let constraints = DateConstraints::new().not_before(date(2024, 1, 1)).not_after(date(2024, 12, 31));
+        assert!(constraints.validate("01/01/2024").is_ok());
+        assert!(constraints.validate("12/31/2024").is_ok());
+        assert_eq!(constraints.check(date(2023, 12, 31)), Err(DateError::TooEarly(date(2024, 1, 1))));
+        assert_eq!(constraints.check(date(2025, 1, 1)), Err(DateError::TooLate(date(2024, 12, 31))));
+    }
+
+    #[test]
+    fn test_constraints_allow_only_business_days() {
+        let constraints = DateConstraints::new().business_days_only();
+        assert!(constraints.validate("03/08/2024").is_ok());
+        assert_eq!(constraints.check(date(2024, 3, 9)), Err(DateError::NotBusinessDay(Weekday::Saturday)));
+        assert_eq!(constraints.check(date(2024, 3, 10)), Err(DateError::NotBusinessDay(Weekday::Sunday)));
+    }
+
+    #[test]
+    fn test_constraints_report_malformed_dates_first() {
+        let err = DateConstraints::new().not_in_future().validate("02/30/2021").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::DayOutOfRange { month: 2, max: 28 }));
+    }
+
+    #[test]
+    fn test_today_is_a_valid_date() {
+        let today = UsDate::today();
+        assert!(today.year() >= 2024);
+        assert_eq!(validate_date(&today.to_string()), Ok(today));
+    }
+
+    #[test]
+    fn test_validate_card_expiry_valid_through_end_of_month() {
+        let expiry = validate_card_expiry("02/24", date(2024, 2, 29)).unwrap();
+        assert_eq!((expiry.month(), expiry.year()), (2, 2024));
+        assert_eq!(expiry.last_day(), date(2024, 2, 29));
+        assert_eq!(expiry.to_string(), "02/24");
+        assert!(validate_card_expiry("12 / 30", date(2024, 2, 29)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_card_expiry_reports_expired_cards() {
+        let err = validate_card_expiry("02/24", date(2024, 3, 1)).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::Expired { month: 2, year: 2024 }));
+        assert_eq!(err.to_string(), "card expired at the end of 02/2024 (at bytes 0..5)");
+    }
+
+    #[test]
+    fn test_validate_card_expiry_reports_month_out_of_range() {
+        let err = validate_card_expiry("13/30", date(2024, 1, 1)).unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Date(DateError::MonthOutOfRange(13)));
+        assert_eq!(err.span(), Span::new(0, 2));
+        assert!(validate_card_expiry("00/30", date(2024, 1, 1)).is_err());
+    }
+
+    #[test]
+    fn test_validate_card_expiry_rejects_malformed_input() {
+        for expiry in ["2/30", "02/2030", "02-30", "0230", "", "١٢/٢٩", "１２/２９"] {
+            let err = validate_card_expiry(expiry, date(2024, 1, 1)).unwrap_err();
+            assert_eq!(err.kind(), &ErrorKind::Date(DateError::MalformedExpiry));
+        }
+    }
+
+    #[test]
+    fn test_generated_dates_and_times() {
+        let mut generator = Generator::new(483);
+        for _ in 0..200 {
+            let date = validate_date(&generator.valid(Field::Date)).unwrap();
+            assert!(date.day() <= days_in_month(date.year(), date.month()).unwrap());
+            assert!(validate_date(&generator.invalid(Field::Date)).is_err());
+            assert!(validate_military_time(&generator.valid(Field::MilitaryTime)).is_ok());
+            assert!(validate_military_time(&generator.invalid(Field::MilitaryTime)).is_err());
+        }
+    }
+}
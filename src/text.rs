@@ -0,0 +1,90 @@
+//! Free-text scanning: words with an odd number of letters ending in "ion".
+
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// Matches words with an odd number of letters ending in "ion".
+static ODD_ION_WORDS_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(?:[a-zA-Z]{2})*[a-zA-Z]ion(?:\b(?:\s|-|\n|\t)*|$)").unwrap());
+
+/// Validates a text string by extracting words that have an odd number of characters and end with "ion".
+///
+/// # Description
+///
+/// * This function checks the input text string for words that:
+///   - Have an odd number of characters
+///   - End with the substring "ion"
+///   - The words can be delimited by spaces, newlines, tabs, or dashes.
+///
+/// * The function extracts all matching words and returns them as a vector of strings.
+///
+/// # Arguments
+///
+/// * `text: &str` - The input string containing the text to be validated and processed.
+///
+/// # Returns
+///
+/// * `Vec<String>` - A vector of strings containing the extracted words that meet the specified criteria.
+pub fn validate_odd_ion_words(text: &str) -> Vec<String> {
+
+    // Extract all words that match the criteria and return them as a vector of strings
+    ODD_ION_WORDS_REGEX
+        .find_iter(text)
+        .map(|mat| mat.as_str().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_odd_ion_words_valid_ablation() {
+        assert_eq!(validate_odd_ion_words("ablation"), vec!["ablation"]);
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_valid_ligation() {
+        assert_eq!(validate_odd_ion_words("ligation"), vec!["ligation"]);
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_valid_option() {
+        assert_eq!(validate_odd_ion_words("option"), vec!["option"]);
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_valid_fusion() {
+        assert_eq!(validate_odd_ion_words("fusion"), vec!["fusion"]);
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_valid_cation() {
+        assert_eq!(validate_odd_ion_words("cation"), vec!["cation"]);
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_invalid_even_chars() {
+        assert_eq!(validate_odd_ion_words("differentiation"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_invalid_not_ion() {
+        assert_eq!(validate_odd_ion_words("evolutione"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_invalid_single_char() {
+        assert_eq!(validate_odd_ion_words("i"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_invalid_no_chars() {
+        assert_eq!(validate_odd_ion_words(""), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_validate_odd_ion_words_invalid_empty_string() {
+        assert_eq!(validate_odd_ion_words(" "), Vec::<String>::new());
+    }
+}
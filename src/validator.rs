@@ -0,0 +1,168 @@
+//! A common interface over the field validators.
+//!
+//! Each field kind has a validator type implementing [`Validator`], so callers such as form
+//! frameworks can hold a `Vec<Box<dyn Validator>>` and drive every check the same way:
+//!
+//! ```
+//! use regular_expressions::validator::{self, Validator};
+//!
+//! let ssn = validator::by_name("ssn").unwrap();
+//! assert_eq!(ssn.normalize("123 45 6788").unwrap(), "123-45-6788");
+//! assert!(ssn.validate("666-12-3456").is_err());
+//! ```
+
+use crate::contact::{EmailValidator, InternationalPhoneValidator, PhoneValidator};
+use crate::error::ValidationError;
+use crate::finance::CurrencyValidator;
+use crate::identity::{NameRosterValidator, SsnValidator};
+use crate::location::{AddressValidator, CityStateZipValidator};
+use crate::security::PasswordValidator;
+use crate::taxpayer::TaxpayerIdValidator;
+use crate::temporal::{DateValidator, MilitaryTimeValidator};
+use crate::web::UrlValidator;
+
+/// Validates and normalizes one kind of form field.
+pub trait Validator: Send + Sync {
+    /// Returns the short, stable name of the field kind, e.g. `"ssn"` or `"email"`.
+    fn name(&self) -> &'static str;
+
+    /// Returns a human readable description of each rule the field must satisfy.
+    fn describe_rules(&self) -> &'static [&'static str];
+
+    /// Checks `input` against the rules of the field kind.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), ValidationError>` - Returns `Ok(())` if the input is valid, or the broken rule otherwise.
+    fn validate(&self, input: &str) -> Result<(), ValidationError>;
+
+    /// Validates `input` and rewrites it in the canonical form for the field kind.
+    ///
+    /// # Description
+    ///
+    /// * The default implementation returns the input unchanged once it validates.
+    /// * Validators override this to canonicalize separators, case and formatting, e.g. an
+    ///   SSN of `123 45 6788` normalizes to `123-45-6788`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String, ValidationError>` - Returns the normalized value if the input is valid, or the
+    ///   broken rule otherwise.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        self.validate(input)?;
+        Ok(input.to_string())
+    }
+
+    /// Validates `input` and returns its named parts, e.g. the `area`, `group` and `serial` of an SSN.
+    ///
+    /// # Description
+    ///
+    /// * The default implementation returns no parts once the input validates.
+    /// * Parts are returned in the order they appear in the canonical form of the value.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<(&'static str, String)>, ValidationError>` - Returns the name and value of each part if
+    ///   the input is valid, or the broken rule otherwise.
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        self.validate(input)?;
+        Ok(Vec::new())
+    }
+}
+
+/// How strictly the separators between the digit groups of SSNs and phone numbers are checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum SeparatorMode {
+    /// Each separator may independently be a dash, a space or nothing, and parentheses around a
+    /// phone area code need not be balanced, e.g. `123-45 6789` and `(206 555-0100`.
+    #[default]
+    Lenient,
+    /// Separators must be used consistently, e.g. `123-45-6789` or `206 555 0100`, and
+    /// parentheses must be balanced.
+    Strict,
+}
+
+/// Returns one instance of every field validator, in the order the interactive prompts use.
+pub fn all() -> Vec<Box<dyn Validator>> {
+    vec![
+        Box::new(SsnValidator),
+        Box::new(PhoneValidator),
+        Box::new(EmailValidator::default()),
+        Box::new(NameRosterValidator),
+        Box::new(DateValidator),
+        Box::new(AddressValidator),
+        Box::new(CityStateZipValidator),
+        Box::new(MilitaryTimeValidator),
+        Box::new(CurrencyValidator),
+        Box::new(UrlValidator::new()),
+        Box::new(PasswordValidator),
+        Box::new(InternationalPhoneValidator::default()),
+        Box::new(TaxpayerIdValidator),
+    ]
+}
+
+/// Looks up a field validator by its [`Validator::name`].
+pub fn by_name(name: &str) -> Option<Box<dyn Validator>> {
+    all().into_iter().find(|validator| validator.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_validators_have_unique_names() {
+        let mut names: Vec<&str> = all().iter().map(|validator| validator.name()).collect();
+        let count = names.len();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), count);
+    }
+
+    #[test]
+    fn test_all_validators_describe_rules() {
+        for validator in all() {
+            assert!(!validator.describe_rules().is_empty(), "{} has no rules", validator.name());
+        }
+    }
+
+    #[test]
+    fn test_by_name_finds_validator() {
+        assert_eq!(by_name("currency").unwrap().name(), "currency");
+    }
+
+    #[test]
+    fn test_by_name_unknown_validator() {
+        assert!(by_name("zodiac").is_none());
+    }
+
+    #[test]
+    fn test_validators_drive_uniformly() {
+        let form: Vec<(Box<dyn Validator>, &str)> = vec![
+            (Box::new(SsnValidator), "123-45-6788"),
+            (Box::new(EmailValidator::default()), "notafed@FBI.gov"),
+            (Box::new(DateValidator), "02-29-2020"),
+            (Box::new(CurrencyValidator), "$1234.5"),
+            (Box::new(UrlValidator::new()), "https://www.example.com"),
+            (Box::new(PasswordValidator), "A1b@c$d2E#"),
+        ];
+        let failures: Vec<&str> = form
+            .iter()
+            .filter(|(validator, input)| validator.validate(input).is_err())
+            .map(|(validator, _)| validator.name())
+            .collect();
+        assert_eq!(failures, vec!["currency"]);
+    }
+
+    #[test]
+    fn test_default_components_are_empty() {
+        assert_eq!(PasswordValidator.components("A1b@c$d2E#").unwrap(), vec![]);
+        assert!(PasswordValidator.components("short").is_err());
+    }
+
+    #[test]
+    fn test_default_normalize_returns_input() {
+        assert_eq!(PasswordValidator.normalize("A1b@c$d2E#").unwrap(), "A1b@c$d2E#");
+    }
+}
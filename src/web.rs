@@ -0,0 +1,392 @@
+//! Web addresses.
+
+use std::fmt;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::{Span, ValidationError};
+use crate::idn;
+use crate::validator::Validator;
+
+/// Matches an optional http(s) scheme, a dotted host name with a 2-6 letter or punycode TLD, and an optional path.
+static URL_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)^(?:http[s]?://)?(?:[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?\.)+(?:[a-zA-Z]{2,6}|xn--[a-zA-Z0-9-]{1,58}[a-zA-Z0-9])(?:/[-a-zA-Z0-9()@:%_+.~#?&=]*)?$").unwrap());
+
+/// The rule a URL broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlError {
+    /// The URL has a scheme other than "http://" or "https://".
+    UnsupportedScheme(String),
+    /// A host label is empty, too long, or contains characters other than letters, digits and hyphens.
+    InvalidHost,
+    /// The top-level domain is missing or is not 2 to 6 letters (or a punycode label).
+    InvalidTopLevelDomain,
+    /// A host with non-ASCII or punycode labels is not a valid internationalized domain name.
+    InvalidInternationalHost,
+    /// The path contains a character that is not allowed.
+    InvalidPath(char),
+    /// The URL does not match the expected format for another reason.
+    Malformed,
+}
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlError::UnsupportedScheme(scheme) => write!(f, "scheme {:?} is not http or https", scheme),
+            UrlError::InvalidHost => write!(f, "host name labels must be letters, digits and inner hyphens"),
+            UrlError::InvalidTopLevelDomain => write!(f, "top-level domain must be 2 to 6 letters"),
+            UrlError::InvalidInternationalHost => write!(f, "host is not a valid internationalized domain name"),
+            UrlError::InvalidPath(ch) => write!(f, "path may not contain {:?}", ch),
+            UrlError::Malformed => write!(f, "URL must be in the form https://www.example.com/path"),
+        }
+    }
+}
+
+/// Validates a URL string by checking if it matches the expected format.
+///
+/// # Description
+///
+/// * This function checks if the input string matches the URL format, which consists of:
+///   - An optional "http://" or "https://" prefix (case-insensitive)
+///   - A domain name with one or more subdomains, each consisting of alphanumeric characters and hyphens, separated by periods
+///   - A top-level domain (TLD) with 2 to 6 alphabetic characters, or a punycode TLD such as "xn--p1ai"
+///   - An optional path with allowed characters: a-z, A-Z, 0-9, -, (, ), @, %, _, +, ., ~, #, ?, &, =
+///
+/// # Arguments
+///
+/// * `url: &str` - The input string containing the URL to validate.
+///
+/// # Returns
+///
+/// * `Result<(), ValidationError>` - Returns `Ok(())` if the input matches the expected URL format, or the
+///   broken rule (a [`UrlError`]) otherwise.
+pub fn validate_url(url: &str) -> Result<(), ValidationError> {
+    if URL_REGEX.is_match(url) {
+        return Ok(());
+    }
+    Err(diagnose_url(url))
+}
+
+/// Explains why `url` does not match the URL format.
+fn diagnose_url(url: &str) -> ValidationError {
+    let host_start = match url.find("://") {
+        Some(end) => {
+            let scheme = &url[..end];
+            if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+                return ValidationError::new(UrlError::UnsupportedScheme(scheme.to_string()), Span::new(0, end));
+            }
+            end + 3
+        }
+        None => 0,
+    };
+
+    let host_end = url[host_start..].find('/').map_or(url.len(), |i| host_start + i);
+    let host = &url[host_start..host_end];
+    let labels: Vec<&str> = host.split('.').collect();
+    let tld = labels[labels.len() - 1];
+    let mut offset = host_start;
+    for label in &labels[..labels.len() - 1] {
+        let valid = !label.is_empty()
+            && label.len() <= 63
+            && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+            && !label.starts_with('-')
+            && !label.ends_with('-');
+        if !valid {
+            return ValidationError::new(UrlError::InvalidHost, Span::new(offset, offset + label.len()));
+        }
+        offset += label.len() + 1;
+    }
+    let letters = (2..=6).contains(&tld.len()) && tld.bytes().all(|b| b.is_ascii_alphabetic());
+    let punycode = tld.get(..4).is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--"));
+    if labels.len() < 2 || !(letters || punycode) {
+        return ValidationError::new(UrlError::InvalidTopLevelDomain, Span::new(host_end - tld.len(), host_end));
+    }
+
+    // The path is a single segment, so any slash after the first is rejected along with other disallowed characters.
+    let path_start = host_end + 1;
+    if path_start <= url.len() {
+        let allowed = "-()@:%_+.~#?&=";
+        if let Some((i, ch)) = url[path_start..].char_indices().find(|(_, ch)| !(ch.is_ascii_alphanumeric() || allowed.contains(*ch))) {
+            let i = path_start + i;
+            return ValidationError::new(UrlError::InvalidPath(ch), Span::new(i, i + ch.len_utf8()));
+        }
+    }
+
+    ValidationError::new(UrlError::Malformed, Span::whole(url))
+}
+
+/// A valid URL whose host may be an internationalized domain name.
+///
+/// # Description
+///
+/// * Produced by [`validate_international_url`].
+/// * Keeps the URL with its host in both Unicode form (`https://bücher.de/kasse`) and ASCII form
+///   (`https://xn--bcher-kva.de/kasse`). Internationalized hosts are lowercased; the rest of the
+///   URL is kept as written.
+/// * Displays in its Unicode form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InternationalUrl {
+    unicode: String,
+    ascii: String,
+}
+
+impl InternationalUrl {
+    /// Returns the URL with its host in Unicode form.
+    pub fn to_unicode(&self) -> &str {
+        &self.unicode
+    }
+
+    /// Returns the URL with its host in ASCII form, as sent over the network.
+    pub fn to_ascii(&self) -> &str {
+        &self.ascii
+    }
+}
+
+impl fmt::Display for InternationalUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.unicode)
+    }
+}
+
+/// Validates a URL whose host may be an internationalized domain name, such as `https://bücher.de`.
+///
+/// # Description
+///
+/// * A host with non-ASCII or punycode labels must be a valid internationalized domain name; see
+///   [`idn`]. The rules of [`validate_url`] are then applied with the host in ASCII form.
+///
+/// # Returns
+///
+/// * `Result<InternationalUrl, ValidationError>` - Returns both forms of the URL if it is valid, or
+///   the broken rule (a [`UrlError`]) otherwise.
+pub fn validate_international_url(url: &str) -> Result<InternationalUrl, ValidationError> {
+    let host_start = url.find("://").map_or(0, |i| i + 3);
+    let host_end = url[host_start..].find('/').map_or(url.len(), |i| host_start + i);
+    let host = &url[host_start..host_end];
+    if !idn::is_internationalized(host) {
+        validate_url(url)?;
+        return Ok(InternationalUrl { unicode: url.to_string(), ascii: url.to_string() });
+    }
+
+    let invalid = || ValidationError::new(UrlError::InvalidInternationalHost, Span::new(host_start, host_end));
+    let (unicode_host, ascii_host) = (idn::domain_to_unicode(host).ok_or_else(invalid)?, idn::domain_to_ascii(host).ok_or_else(invalid)?);
+    let ascii = format!("{}{}{}", &url[..host_start], ascii_host, &url[host_end..]);
+    validate_url(&ascii).map_err(|err| {
+        // Point errors at the host as written, and shift errors after it back by the change in its length.
+        let span = err.span();
+        let ascii_host_end = host_start + ascii_host.len();
+        let span = match span.start {
+            start if start >= ascii_host_end => Span::new(start - ascii_host_end + host_end, span.end - ascii_host_end + host_end),
+            start if start >= host_start => Span::new(host_start, host_end),
+            _ => span,
+        };
+        ValidationError::new(err.kind().clone(), span)
+    })?;
+    Ok(InternationalUrl { unicode: format!("{}{}{}", &url[..host_start], unicode_host, &url[host_end..]), ascii })
+}
+
+/// The [`Validator`] for URLs; see [`validate_url`] and [`validate_international_url`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UrlValidator {
+    unicode: bool,
+}
+
+impl UrlValidator {
+    /// Creates a validator of URLs with ASCII hosts.
+    pub fn new() -> UrlValidator {
+        UrlValidator::default()
+    }
+
+    /// Sets whether URLs with internationalized hosts such as `https://bücher.de` are accepted.
+    /// They are normalized to the Unicode form of their host.
+    pub fn with_unicode(mut self, unicode: bool) -> UrlValidator {
+        self.unicode = unicode;
+        self
+    }
+
+    /// Returns whether URLs with internationalized hosts are accepted.
+    pub fn unicode(&self) -> bool {
+        self.unicode
+    }
+
+    /// Validates `input` and returns it with its host in Unicode form.
+    fn unicode_form(&self, input: &str) -> Result<String, ValidationError> {
+        if self.unicode {
+            return validate_international_url(input).map(|url| url.unicode);
+        }
+        validate_url(input)?;
+        Ok(input.to_string())
+    }
+}
+
+impl Validator for UrlValidator {
+    fn name(&self) -> &'static str {
+        "url"
+    }
+
+    fn describe_rules(&self) -> &'static [&'static str] {
+        &[
+            "An optional \"http://\" or \"https://\" prefix (case-insensitive).",
+            "A domain name of labels made of letters, digits and inner hyphens, separated by periods.",
+            "A top-level domain (TLD) with 2 to 6 alphabetic characters.",
+            "An optional path with allowed characters: a-z, A-Z, 0-9, -, (, ), @, %, _, +, ., ~, #, ?, &, =",
+        ]
+    }
+
+    fn validate(&self, input: &str) -> Result<(), ValidationError> {
+        if self.unicode {
+            return validate_international_url(input).map(|_| ());
+        }
+        validate_url(input)
+    }
+
+    /// Lowercases the scheme and host; the path is case-sensitive and kept as written.
+    fn normalize(&self, input: &str) -> Result<String, ValidationError> {
+        let input = &self.unicode_form(input)?;
+        let host_start = input.find("://").map_or(0, |i| i + 3);
+        let host_end = input[host_start..].find('/').map_or(input.len(), |i| host_start + i);
+        Ok(format!("{}{}", input[..host_end].to_ascii_lowercase(), &input[host_end..]))
+    }
+
+    fn components(&self, input: &str) -> Result<Vec<(&'static str, String)>, ValidationError> {
+        let unicode = self.unicode_form(input)?;
+        let (scheme, rest) = unicode.split_once("://").unwrap_or(("", &unicode));
+        let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+        let mut components =
+            vec![("scheme", scheme.to_ascii_lowercase()), ("host", host.to_ascii_lowercase()), ("path", path.to_string())];
+        if self.unicode {
+            components.push(("ascii-host", idn::domain_to_ascii(host).unwrap_or_else(|| host.to_ascii_lowercase())));
+        }
+        Ok(components)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+
+    #[test]
+    fn test_validate_international_url() {
+        let url = validate_international_url("https://Bücher.de/kasse?id=7").unwrap();
+        assert_eq!(url.to_unicode(), "https://bücher.de/kasse?id=7");
+        assert_eq!(url.to_ascii(), "https://xn--bcher-kva.de/kasse?id=7");
+        assert_eq!(validate_international_url("xn--e1afmkfd.xn--p1ai").unwrap().to_unicode(), "пример.рф");
+        assert_eq!(validate_international_url("www.example.com").unwrap().to_ascii(), "www.example.com");
+        assert!(validate_url("https://bücher.de").is_err());
+        assert!(validate_url("https://xn--bcher-kva.de").is_ok());
+    }
+
+    #[test]
+    fn test_validate_international_url_reports_errors() {
+        let err = validate_international_url("https://bü_cher.de").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Url(UrlError::InvalidInternationalHost));
+        assert_eq!(err.span(), Span::new(8, 19));
+        let err = validate_international_url("https://bücher.de/a b").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Url(UrlError::InvalidPath(' ')));
+        assert_eq!(err.span(), Span::new(20, 21));
+        let err = validate_international_url("ftp://bücher.de").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Url(UrlError::UnsupportedScheme("ftp".to_string())));
+    }
+
+    #[test]
+    fn test_url_validator_with_unicode() {
+        let validator = UrlValidator::new().with_unicode(true);
+        assert_eq!(validator.normalize("HTTPS://XN--BCHER-KVA.DE/Kasse").unwrap(), "https://bücher.de/Kasse");
+        let components = validator.components("https://bücher.de/kasse").unwrap();
+        assert_eq!(components[1], ("host", "bücher.de".to_string()));
+        assert_eq!(components[3], ("ascii-host", "xn--bcher-kva.de".to_string()));
+        assert!(UrlValidator::new().validate("https://bücher.de").is_err());
+    }
+
+    #[test]
+    fn test_validate_url_valid_http() {
+        assert!(validate_url("http://www.example.com").is_ok());
+    }
+
+    #[test]
+    fn test_validate_url_valid_https() {
+        assert!(validate_url("https://www.example.com").is_ok());
+    }
+
+    #[test]
+    fn test_validate_url_valid_no_protocol() {
+        assert!(validate_url("www.example.com").is_ok());
+    }
+
+    #[test]
+    fn test_validate_url_valid_subdomain() {
+        assert!(validate_url("https://subdomain.example.com").is_ok());
+    }
+
+    #[test]
+    fn test_validate_url_valid_path_and_query() {
+        assert!(validate_url("https://www.example.com/path?query=value").is_ok());
+    }
+
+    #[test]
+    fn test_validate_url_invalid_missing_tld() {
+        assert!(validate_url("http://www.example").is_err());
+    }
+
+    #[test]
+    fn test_validate_url_invalid_space_in_url() {
+        assert!(validate_url("https://www.exa mple.com").is_err());
+    }
+
+    #[test]
+    fn test_validate_url_invalid_double_slash() {
+        assert!(validate_url("https://www.example.com//path").is_err());
+    }
+
+    #[test]
+    fn test_validate_url_invalid_extra_dot() {
+        assert!(validate_url("https://www..example.com").is_err());
+    }
+
+    #[test]
+    fn test_validate_url_invalid_wrong_protocol() {
+        assert!(validate_url("ftp://www.example.com").is_err());
+    }
+
+    #[test]
+    fn test_validate_url_reports_unsupported_scheme() {
+        let err = validate_url("ftp://www.example.com").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Url(UrlError::UnsupportedScheme("ftp".to_string())));
+        assert_eq!(err.span(), Span::new(0, 3));
+    }
+
+    #[test]
+    fn test_validate_url_reports_invalid_host() {
+        let err = validate_url("https://www.exa mple.com").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Url(UrlError::InvalidHost));
+        assert_eq!(err.span(), Span::new(12, 20));
+    }
+
+    #[test]
+    fn test_validate_url_reports_invalid_top_level_domain() {
+        let err = validate_url("http://www.example").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Url(UrlError::InvalidTopLevelDomain));
+    }
+
+    #[test]
+    fn test_validate_url_reports_invalid_path() {
+        let err = validate_url("https://www.example.com//path").unwrap_err();
+        assert_eq!(err.kind(), &ErrorKind::Url(UrlError::InvalidPath('/')));
+        assert_eq!(err.span(), Span::new(24, 25));
+    }
+
+    #[test]
+    fn test_url_validator_lowercases_scheme_and_host() {
+        assert_eq!(UrlValidator::new().normalize("HTTPS://WWW.Example.com/Path").unwrap(), "https://www.example.com/Path");
+    }
+
+    #[test]
+    fn test_url_validator_components() {
+        let components = UrlValidator::new().components("HTTPS://www.Example.com/path?query=value").unwrap();
+        assert_eq!(
+            components,
+            vec![("scheme", "https".to_string()), ("host", "www.example.com".to_string()), ("path", "/path?query=value".to_string())]
+        );
+    }
+}
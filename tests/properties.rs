@@ -0,0 +1,280 @@
+//! Property-based tests of invariants that hold for every input, not just the hand-written cases
+//! in each module's unit tests.
+//!
+//! Counterexamples proptest shrinks from failures are saved in `properties.proptest-regressions` and
+//! replayed first on every run; the interesting ones are also pinned below as plain tests.
+
+use proptest::prelude::*;
+use regular_expressions::contact::{format_phone_number, EmailLevel, EmailValidator, PhoneFormat};
+use regular_expressions::generate::{Field, Generator};
+use regular_expressions::pseudonym::Pseudonymizer;
+use regular_expressions::redact::{redact, Mask};
+use regular_expressions::scan;
+use regular_expressions::temporal::{parse_date, validate_card_expiry, DateOrder};
+use regular_expressions::validator::{self, SeparatorMode, Validator};
+use regular_expressions::{
+    validate_currency, validate_date, validate_international_email, validate_international_url, validate_military_time,
+    validate_phone_number, validate_phone_number_with, validate_ssn, validate_ssn_with,
+};
+use regular_expressions::{MilitaryTime, Ssn, UsDate, UsdAmount};
+
+/// Returns short strings of ASCII, Arabic-Indic and fullwidth digits and separators. The regex
+/// `\d` matches all three, but arbitrary strings almost never contain such digit-shaped input.
+fn unicode_digits() -> impl Strategy<Value = String> {
+    "[0-9٠-٩０-９ /:-]{0,12}"
+}
+
+/// Returns a mix of arbitrary strings and generated valid and invalid samples of `field`, so
+/// properties are exercised on inputs close to the accepted formats as well as on noise.
+fn samples(field: Field) -> impl Strategy<Value = String> {
+    prop_oneof![
+        any::<String>(),
+        "[0-9 ()+$,./:@-]{0,16}",
+        unicode_digits(),
+        any::<u64>().prop_map(move |seed| Generator::new(seed).valid(field)),
+        any::<u64>().prop_map(move |seed| Generator::new(seed).invalid(field)),
+    ]
+}
+
+/// Returns the fields that have a [`Validator`](regular_expressions::Validator); odd-ion words are
+/// found by a free-text search instead.
+fn validated_fields() -> impl Strategy<Value = Field> {
+    prop::sample::select(Field::ALL.into_iter().filter(|field| *field != Field::OddIonWord).collect::<Vec<_>>())
+}
+
+/// Checks that normalizing `input` succeeds exactly when validating it does, and that the
+/// normalized form is itself valid and normalizes to itself.
+fn check_normalize(name: &str, input: &str) -> Result<(), TestCaseError> {
+    let validator = validator::by_name(name).unwrap();
+    let normalized = validator.normalize(input);
+    prop_assert_eq!(normalized.is_ok(), validator.validate(input).is_ok());
+    if let Ok(normalized) = normalized {
+        prop_assert!(validator.validate(&normalized).is_ok(), "{} normalized {:?} to invalid {:?}", name, input, normalized);
+        prop_assert_eq!(validator.normalize(&normalized).unwrap(), normalized);
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn normalize_agrees_with_validate(field in validated_fields(), seed in any::<u64>(), noise in any::<String>()) {
+        let mut generator = Generator::new(seed);
+        for input in [generator.valid(field), generator.invalid(field), noise] {
+            check_normalize(field.name(), &input)?;
+        }
+    }
+
+    #[test]
+    fn email_levels_normalize_consistently(level in prop::sample::select(vec![EmailLevel::Html5, EmailLevel::Rfc5322, EmailLevel::Project]), input in samples(Field::Email)) {
+        let validator = EmailValidator::new(level);
+        prop_assert_eq!(validator.normalize(&input).is_ok(), validator.validate(&input).is_ok());
+        if let Ok(normalized) = validator.normalize(&input) {
+            prop_assert_eq!(validator.normalize(&normalized).unwrap(), normalized);
+        }
+    }
+
+    #[test]
+    fn international_forms_round_trip(prefix in "[a-zé用.]{1,6}", host in "[a-zü例.-]{1,12}", tld in "(de|com|рф|xn--p1ai)") {
+        let domain = format!("{}.{}", host, tld);
+        if let Ok(email) = validate_international_email(&format!("{}@{}", prefix, domain), EmailLevel::Project) {
+            let again = validate_international_email(&email.to_ascii(), EmailLevel::Project).unwrap();
+            prop_assert_eq!(again.to_unicode(), email.to_unicode());
+        }
+        if let Ok(url) = validate_international_url(&format!("https://{}/x", domain)) {
+            prop_assert_eq!(validate_international_url(url.to_ascii()).unwrap(), url.clone());
+            prop_assert_eq!(validate_international_url(url.to_unicode()).unwrap(), url);
+        }
+    }
+
+    #[test]
+    fn validators_never_panic(input in prop_oneof![any::<String>(), unicode_digits()]) {
+        for validator in validator::all() {
+            let _ = validator.validate(&input);
+            let _ = validator.components(&input);
+        }
+        let _ = regular_expressions::validate_odd_ion_words(&input);
+        for order in [DateOrder::MonthFirst, DateOrder::DayFirst, DateOrder::Either] {
+            let _ = parse_date(&input, order);
+        }
+        let _ = validate_card_expiry(&input, UsDate::new(2024, 1, 1).unwrap());
+        let _ = scan::scan(&input);
+        let _ = redact(&input, Mask::Partial);
+    }
+
+    #[test]
+    fn error_spans_lie_within_the_input((field, input) in validated_fields().prop_flat_map(|field| (Just(field), samples(field)))) {
+        if let Err(err) = validator::by_name(field.name()).unwrap().validate(&input) {
+            let span = err.span();
+            prop_assert!(span.start <= span.end && span.end <= input.len());
+            prop_assert!(input.is_char_boundary(span.start) && input.is_char_boundary(span.end));
+        }
+    }
+
+    #[test]
+    fn ssn_display_round_trips(area in 0u16..1000, group in 0u8..100, serial in 0u16..10000) {
+        if let Some(ssn) = Ssn::new(area, group, serial) {
+            prop_assert_eq!(validate_ssn(&ssn.to_string()).unwrap(), ssn);
+        }
+    }
+
+    #[test]
+    fn ssn_parses_generated_samples(input in samples(Field::Ssn)) {
+        if let Ok(ssn) = validate_ssn(&input) {
+            prop_assert_eq!(Ssn::new(ssn.area(), ssn.group(), ssn.serial()), Some(ssn));
+        }
+    }
+
+    #[test]
+    fn strict_separators_accept_a_subset_of_lenient(ssn in samples(Field::Ssn), phone in samples(Field::Phone)) {
+        if let Ok(strict) = validate_ssn_with(&ssn, SeparatorMode::Strict) {
+            prop_assert_eq!(validate_ssn(&ssn).unwrap(), strict);
+        }
+        if let Ok(strict) = validate_phone_number_with(&phone, SeparatorMode::Strict) {
+            prop_assert_eq!(validate_phone_number(&phone).unwrap(), strict);
+        }
+    }
+
+    #[test]
+    fn generated_samples_use_consistent_separators(seed in any::<u64>()) {
+        let mut generator = Generator::new(seed);
+        let (ssn, phone) = (generator.valid(Field::Ssn), generator.valid(Field::Phone));
+        prop_assert!(validate_ssn_with(&ssn, SeparatorMode::Strict).is_ok(), "{}", ssn);
+        prop_assert!(validate_phone_number_with(&phone, SeparatorMode::Strict).is_ok(), "{}", phone);
+    }
+
+    #[test]
+    fn us_date_display_round_trips(year in any::<u16>(), month in 0u8..14, day in 0u8..33) {
+        if let Some(date) = UsDate::new(year, month, day) {
+            prop_assert_eq!(validate_date(&date.to_string()).unwrap(), date);
+        }
+    }
+
+    #[test]
+    fn parse_date_reads_every_format_alike(year in 0u16..10000, month in 1u8..13, day in 1u8..32) {
+        if let Some(date) = UsDate::new(year, month, day) {
+            let name = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"][usize::from(month) - 1];
+            let us = parse_date(&date.to_string(), DateOrder::MonthFirst).unwrap();
+            prop_assert_eq!(us.date(), date);
+            prop_assert_eq!(us.is_ambiguous(), day <= 12 && day != month);
+            prop_assert_eq!(parse_date(&date.iso8601(), DateOrder::Either).unwrap().date(), date);
+            prop_assert_eq!(parse_date(&format!("{}.{}.{:04}", day, month, year), DateOrder::DayFirst).unwrap().date(), date);
+            prop_assert_eq!(parse_date(&format!("{} {}, {:04}", name, day, year), DateOrder::Either).unwrap().date(), date);
+        }
+    }
+
+    #[test]
+    fn parse_date_accepts_what_validate_date_does(input in samples(Field::Date)) {
+        if let Ok(date) = validate_date(&input) {
+            prop_assert_eq!(parse_date(&input, DateOrder::MonthFirst).unwrap().date(), date);
+        }
+    }
+
+    #[test]
+    fn us_date_parses_generated_samples(input in samples(Field::Date)) {
+        if let Ok(date) = validate_date(&input) {
+            prop_assert_eq!(UsDate::new(date.year(), date.month(), date.day()), Some(date));
+        }
+    }
+
+    #[test]
+    fn military_time_display_round_trips(hour in 0u8..30, minute in 0u8..70) {
+        if let Some(time) = MilitaryTime::new(hour, minute) {
+            prop_assert_eq!(validate_military_time(&time.to_string()).unwrap(), time);
+        }
+    }
+
+    #[test]
+    fn usd_amount_display_round_trips(cents in any::<u64>()) {
+        let amount = UsdAmount::from_cents(cents);
+        prop_assert_eq!(validate_currency(&amount.to_string()).unwrap(), amount);
+    }
+
+    #[test]
+    fn currency_keeps_every_written_digit(input in prop_oneof![samples(Field::Currency), "\\$[0-9٠-٩０-９,.]{1,8}"]) {
+        if let Ok(amount) = validate_currency(&input) {
+            // Amounts without cents are whole dollars, so their digits gain two zeros as cents.
+            let cents = if input.contains('.') { "" } else { "00" };
+            let written: String = input.chars().filter(|ch| ch.is_numeric()).chain(cents.chars()).collect();
+            let normalized = amount.cents().to_string();
+            prop_assert_eq!(written.trim_start_matches('0'), normalized.trim_start_matches('0'), "{}", input);
+        }
+    }
+
+    #[test]
+    fn phone_extensions_survive_normalization(seed in any::<u64>(), marker in prop::sample::select(vec![" x", " ext. ", ", #"]), extension in "[0-9٠-٩０-９]{1,6}") {
+        let input = format!("{}{}{}", Generator::new(seed).valid(Field::Phone), marker, extension);
+        if let Ok(number) = validate_phone_number(&input) {
+            prop_assert_eq!(number.extension().map(|extension| extension.to_string()), Some(extension.clone()));
+            let suffix = format!(" ext. {}", extension);
+            prop_assert!(format_phone_number(&number, PhoneFormat::National).ends_with(&suffix));
+        }
+    }
+
+    #[test]
+    fn scan_findings_are_ordered_disjoint_and_valid(text in "([a-z ,.]{0,8}|123-45-6788|\\(206\\) 301-0100|jo@example\\.com|02/29/2020|\\$1,234\\.56|98101){0,8}") {
+        let findings = scan::scan(&text);
+        for pair in findings.windows(2) {
+            prop_assert!(pair[0].span().end <= pair[1].span().start);
+        }
+        for finding in &findings {
+            prop_assert_eq!(&text[finding.span().start..finding.span().end], finding.as_str());
+        }
+    }
+
+    #[test]
+    fn pseudonyms_reveal_the_original(key in any::<Vec<u8>>(), input in samples(Field::Ssn)) {
+        let pseudonymizer = Pseudonymizer::new(&key);
+        if let Ok(ssn) = validate_ssn(&input) {
+            let pseudonym = pseudonymizer.pseudonymize_ssn(&input).unwrap();
+            prop_assert_eq!(pseudonymizer.reveal_ssn(&pseudonym.to_string()).unwrap(), ssn);
+        }
+    }
+
+    #[test]
+    fn phone_pseudonyms_reveal_the_original(key in any::<Vec<u8>>(), input in samples(Field::Phone)) {
+        let pseudonymizer = Pseudonymizer::new(&key);
+        if let Ok(phone) = validate_phone_number(&input) {
+            let pseudonym = pseudonymizer.pseudonymize_phone(&input).unwrap();
+            let revealed = pseudonymizer.reveal_phone(&format_phone_number(&pseudonym, PhoneFormat::National)).unwrap();
+            prop_assert_eq!(revealed, phone);
+        }
+    }
+}
+
+#[test]
+fn regression_us_date_with_five_digit_year() {
+    // `UsDate::new(10000, 1, 1)` displayed as "01/01/10000", which `validate_date` rejects.
+    assert_eq!(UsDate::new(10000, 1, 1), None);
+    assert_eq!(UsDate::new(9999, 12, 31).unwrap().to_string(), "12/31/9999");
+}
+
+#[test]
+fn regression_address_ending_in_multibyte_whitespace() {
+    // Diagnosing "0\u{a0}" sliced the input inside the two-byte no-break space.
+    let err = regular_expressions::validate_address("0\u{a0}").unwrap_err();
+    assert_eq!((err.span().start, err.span().end), (3, 3));
+}
+
+#[test]
+fn regression_validators_on_non_ascii_digits() {
+    // `\d` matched Arabic-Indic and fullwidth digits, which `str::parse` then failed on and
+    // `unwrap` panicked. Each input is the counterexample proptest shrank to for that function.
+    assert!(validate_ssn("000٠0０0٠０").is_err());
+    assert!(validate_ssn("٠0０００٠00٠").is_err());
+    assert!(validate_date("０٠-00-０٠０٠").is_err());
+    assert!(validate_military_time("٠٠０0").is_err());
+    // `\d` also let these through, but the value was read from ASCII digits only, so `$١٢٣` was
+    // $0.00 and the extension `x١٢` was dropped.
+    assert!(validate_currency("$١٢٣").is_err());
+    assert!(validate_phone_number("206-301-1473 x١٢").is_err());
+    assert!(regular_expressions::validate_taxpayer_id("0٠0００٠０٠٠").is_err());
+    assert!(parse_date("0-٠-０00０", DateOrder::MonthFirst).is_err());
+    assert!(validate_card_expiry("００/００", UsDate::new(2024, 1, 1).unwrap()).is_err());
+}
+
+#[test]
+fn regression_scan_of_non_ascii_digits() {
+    // The scanner passed the digits to `validate_ssn`, which panicked on them.
+    assert!(scan::scan("０0٠0٠0０٠0").is_empty());
+    assert_eq!(redact("０0٠0٠0０٠0", Mask::Partial), "０0٠0٠0０٠0");
+}